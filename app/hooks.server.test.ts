@@ -0,0 +1,99 @@
+import { afterEach, describe, expect, it, vi } from "vite-plus/test";
+
+vi.mock("$hatk/client", () => ({ callXrpc: vi.fn() }));
+
+import { callXrpc } from "$hatk/client";
+import { handle, prefersJson } from "./hooks.server";
+
+const callXrpcMock = vi.mocked(callXrpc);
+
+describe("prefersJson", () => {
+  it("takes application/json on its own", () => {
+    expect(prefersJson("application/json")).toBe(true);
+  });
+
+  it("keeps browsers and wildcards on the page", () => {
+    expect(prefersJson("text/html")).toBe(false);
+    const browser = "text/html,application/xhtml+xml,application/xml;q=0.9,*/*;q=0.8";
+    expect(prefersJson(browser)).toBe(false);
+    expect(prefersJson("*/*")).toBe(false);
+  });
+
+  it("treats a missing Accept header as html", () => {
+    expect(prefersJson(null)).toBe(false);
+    expect(prefersJson("")).toBe(false);
+  });
+
+  it("ranks the two by q-value", () => {
+    expect(prefersJson("text/html;q=0.5, application/json")).toBe(true);
+    expect(prefersJson("application/json;q=0.9, text/html")).toBe(false);
+    expect(prefersJson("application/json; q=0.8, text/html; q=0.8")).toBe(false);
+    expect(prefersJson("Application/JSON;Q=0.4")).toBe(true);
+  });
+
+  it("never picks json it was told not to send", () => {
+    expect(prefersJson("application/json;q=0")).toBe(false);
+    expect(prefersJson("application/json;q=nope")).toBe(false);
+  });
+});
+
+describe("handle", () => {
+  afterEach(() => {
+    callXrpcMock.mockReset();
+  });
+
+  const did = "did:plc:alice";
+  const request = (path: string, accept?: string) => {
+    const url = new URL(path, "https://status.example");
+    const headers: Record<string, string> = accept ? { accept } : {};
+    return { url, request: new Request(url, { headers }) } as never;
+  };
+  const page = () =>
+    vi.fn(async () => new Response("<html></html>", { headers: { "content-type": "text/html" } }));
+
+  it("answers /@{handle} with the status json when asked for json", async () => {
+    callXrpcMock.mockResolvedValue({
+      items: [
+        {
+          uri: `at://${did}/io.zzstoatzz.status.record/3kabc`,
+          did,
+          handle: "alice.test",
+          emoji: "🚀",
+          createdAt: "2025-03-01T12:00:00.000Z",
+          indexedAt: "2025-03-01T12:00:02.000Z",
+          expired: false,
+        },
+      ],
+    } as never);
+    const resolve = page();
+
+    const res = await handle({ event: request(`/@${did}`, "application/json"), resolve });
+
+    expect(resolve).not.toHaveBeenCalled();
+    expect(res.headers.get("content-type")).toContain("application/json");
+    expect(res.headers.get("vary")).toBe("Accept");
+    expect(await res.json()).toMatchObject({ status: "known", did, emoji: "🚀" });
+  });
+
+  it("renders the page for html and for a missing Accept, varying on Accept", async () => {
+    for (const accept of ["text/html", undefined]) {
+      const resolve = page();
+
+      const res = await handle({ event: request("/@alice.test", accept), resolve });
+
+      expect(resolve).toHaveBeenCalledTimes(1);
+      expect(res.headers.get("content-type")).toBe("text/html");
+      expect(res.headers.get("vary")).toBe("Accept");
+    }
+    expect(callXrpcMock).not.toHaveBeenCalled();
+  });
+
+  it("leaves other routes alone", async () => {
+    const resolve = page();
+
+    const res = await handle({ event: request("/feed", "application/json"), resolve });
+
+    expect(resolve).toHaveBeenCalledTimes(1);
+    expect(res.headers.get("vary")).toBeNull();
+  });
+});
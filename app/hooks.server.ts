@@ -0,0 +1,53 @@
+import type { Handle } from "@sveltejs/kit";
+import { handleStatusResponse } from "$lib/server/status";
+import { isNotModified, notModified } from "$lib/utils/http";
+
+const PROFILE_PATH = /^\/@([^/]+)\/?$/;
+
+// true only when the client explicitly ranks application/json above text/html.
+// browsers and bare `*/*` fetches keep getting the page.
+export function prefersJson(accept: string | null): boolean {
+  if (!accept) return false;
+  let json = 0;
+  let html = 0;
+  for (const part of accept.split(",")) {
+    const [type, ...params] = part.trim().toLowerCase().split(";");
+    const qParam = params.map((p) => p.trim()).find((p) => p.startsWith("q="));
+    const q = qParam ? Number(qParam.slice(2)) || 0 : 1;
+    if (type.trim() === "application/json") json = Math.max(json, q);
+    if (type.trim() === "text/html") html = Math.max(html, q);
+  }
+  return json > 0 && json > html;
+}
+
+const respond: Handle = async ({ event, resolve }) => {
+  const profile = PROFILE_PATH.exec(event.url.pathname);
+  if (!profile) return resolve(event);
+
+  // /@{handle} with Accept: application/json gets the same body as /@{handle}/json
+  if (prefersJson(event.request.headers.get("accept"))) {
+    const response = await handleStatusResponse(decodeURIComponent(profile[1]));
+    response.headers.set("vary", "Accept");
+    return response;
+  }
+
+  const response = await resolve(event);
+  response.headers.append("vary", "Accept");
+  return response;
+};
+
+export const handle: Handle = async ({ event, resolve }) => {
+  const response = await respond({ event, resolve });
+
+  // pages that set Last-Modified / ETag (share and profile pages, status json)
+  // answer crawlers' revalidation with an empty 304
+  const { method, headers } = event.request;
+  if (
+    (method === "GET" || method === "HEAD") &&
+    response.status === 200 &&
+    isNotModified(headers, response.headers)
+  ) {
+    return notModified(response);
+  }
+  return response;
+};
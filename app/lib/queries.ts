@@ -10,6 +10,37 @@ export const recentFeedQuery = (limit = 50, f?: Fetch) =>
     staleTime: 60_000,
   });
 
+export const currentFeedQuery = (limit = 50, f?: Fetch) =>
+  queryOptions({
+    queryKey: ["getFeed", "current"],
+    queryFn: () => callXrpc("dev.hatk.getFeed", { feed: "current", limit }, f),
+    staleTime: 60_000,
+  });
+
+// the archive's first day; it only moves if that status is deleted, so cache it long
+export const oldestStatusQuery = (f?: Fetch) =>
+  queryOptions({
+    queryKey: ["getFeed", "oldest"],
+    queryFn: () => callXrpc("dev.hatk.getFeed", { feed: "oldest", limit: 1 }, f),
+    staleTime: 60 * 60_000,
+  });
+
+export const archiveFeedQuery = (date: string, mode: string, limit = 50, f?: Fetch) =>
+  queryOptions({
+    queryKey: ["getFeed", "archive", date, mode],
+    queryFn: () =>
+      callXrpc("dev.hatk.getFeed", { feed: "archive", date, mode, limit }, f),
+    staleTime: 60_000,
+  });
+
+export const tagFeedQuery = (tag: string, limit = 50, f?: Fetch) =>
+  queryOptions({
+    queryKey: ["getFeed", "tag", tag],
+    queryFn: () =>
+      callXrpc("dev.hatk.getFeed", { feed: "tag", tag, limit }, f),
+    staleTime: 60_000,
+  });
+
 export const actorFeedQuery = (did: string, limit = 50, f?: Fetch) =>
   queryOptions({
     queryKey: ["getFeed", "actor", did],
@@ -0,0 +1,33 @@
+import { describe, expect, it } from "vite-plus/test";
+import { ROUTE_DOCS } from "./docs";
+
+// route files → the paths they serve, with [param] spelled {param} as in the docs
+const routePaths = (files: Record<string, unknown>) =>
+  Object.keys(files).map(
+    (file) =>
+      file
+        .replace(/^.*\/routes/, "")
+        .replace(/\/\+(server\.ts|page\.svelte)$/, "")
+        .replace(/\[(\w+)\]/g, "{$1}") || "/",
+  );
+
+const endpoints = routePaths(import.meta.glob("../../routes/**/+server.ts"));
+const pages = routePaths(import.meta.glob("../../routes/**/+page.svelte"));
+
+describe("ROUTE_DOCS", () => {
+  it("documents every +server endpoint", () => {
+    const documented = new Set(ROUTE_DOCS.map((doc) => doc.path));
+    expect(endpoints.filter((path) => !documented.has(path))).toEqual([]);
+    expect(endpoints).toContain("/api/docs");
+  });
+
+  it("only documents routes that exist", () => {
+    const routes = new Set([...endpoints, ...pages]);
+    expect(ROUTE_DOCS.map((doc) => doc.path).filter((path) => !routes.has(path))).toEqual([]);
+  });
+
+  it("lists each method and path once", () => {
+    const keys = ROUTE_DOCS.map((doc) => `${doc.method} ${doc.path}`);
+    expect(new Set(keys).size).toBe(keys.length);
+  });
+});
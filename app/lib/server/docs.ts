@@ -0,0 +1,248 @@
+// the plain http endpoints, next to the handlers they describe. xrpc methods are
+// documented by their lexicons; these are the routes that aren't. served as json at
+// /api/docs and as a page at /docs — add an entry when you add a route.
+
+export interface ParamDoc {
+  name: string;
+  in: "path" | "query" | "header" | "body";
+  description: string;
+  required?: boolean;
+}
+
+export interface RouteDoc {
+  method: "GET" | "POST";
+  // sveltekit-style: {name} is a path parameter
+  path: string;
+  summary: string;
+  // every route here is public; writes go through xrpc with an oauth session
+  auth: "none";
+  params: ParamDoc[];
+  // content type of a successful response
+  returns: string;
+  example?: unknown;
+  errors?: string[];
+}
+
+const HANDLE_PARAM: ParamDoc = {
+  name: "handle",
+  in: "path",
+  description: "a handle (alice.bsky.social, with or without @) or a DID",
+  required: true,
+};
+
+const THEME_PARAM: ParamDoc = {
+  name: "theme",
+  in: "query",
+  description: "dark (default) or light",
+};
+
+const STATUS_PARAMS: ParamDoc[] = [
+  { name: "did", in: "path", description: "the author's DID", required: true },
+  { name: "rkey", in: "path", description: "the status record's key", required: true },
+];
+
+const CURRENT_STATUS_EXAMPLE = {
+  status: "known",
+  uri: "at://did:plc:abc123/io.zzstoatzz.status.record/3kabc",
+  did: "did:plc:abc123",
+  handle: "alice.bsky.social",
+  emoji: "🚀",
+  text: "shipping #status",
+  since: "2025-03-01T12:00:00.000Z",
+  expires: "2025-03-01T14:00:00.000Z",
+  expiresInSeconds: 3600,
+  indexedAt: "2025-03-01T12:00:02.000Z",
+};
+
+export const ROUTE_DOCS: RouteDoc[] = [
+  {
+    method: "GET",
+    path: "/@{handle}/json",
+    summary:
+      'the actor\'s current status, or { status: "unknown", did } when they have none right now. ' +
+      "/@{handle} with Accept: application/json returns the same body.",
+    auth: "none",
+    params: [HANDLE_PARAM],
+    returns: "application/json",
+    example: CURRENT_STATUS_EXAMPLE,
+    errors: ["404 handle_not_found", "404 did_not_found", "502 upstream_failed"],
+  },
+  {
+    method: "GET",
+    path: "/@{handle}/calendar.ics",
+    summary: "the actor's last 500 statuses as an iCalendar feed, one event per status",
+    auth: "none",
+    params: [HANDLE_PARAM],
+    returns: "text/calendar",
+    errors: ["404 unknown account", "502 resolver or index unavailable"],
+  },
+  {
+    method: "GET",
+    path: "/status/{did}/{rkey}/json",
+    summary: "one shared status, as shown on its share page",
+    auth: "none",
+    params: STATUS_PARAMS,
+    returns: "application/json",
+    example: {
+      uri: "at://did:plc:abc123/io.zzstoatzz.status.record/3kabc",
+      did: "did:plc:abc123",
+      handle: "alice.bsky.social",
+      emoji: "custom:bufo-party",
+      emojiUrl: "https://find-bufo.com/e/bufo-party.png",
+      text: "shipping #status with @bob.bsky.social",
+      tags: ["status"],
+      mentions: ["bob.bsky.social"],
+      createdAt: "2025-03-01T12:00:00.000Z",
+      expired: false,
+      indexedAt: "2025-03-01T12:00:02.000Z",
+      shareUrl: "https://status.zzstoatzz.io/status/did:plc:abc123/3kabc",
+      profileUrl: "https://bsky.app/profile/alice.bsky.social",
+    },
+    errors: ["400 invalid_did", "404 status_not_found", "502 upstream_failed"],
+  },
+  {
+    method: "GET",
+    path: "/api/wrapped/{handle}/{week}",
+    summary: "a week of the actor's statuses summarized: top emoji, longest status, busiest day",
+    auth: "none",
+    params: [
+      HANDLE_PARAM,
+      { name: "week", in: "path", description: "ISO week, e.g. 2025-W09", required: true },
+    ],
+    returns: "application/json",
+    example: {
+      handle: "alice.bsky.social",
+      did: "did:plc:abc123",
+      week: "2025-W09",
+      start: "2025-02-24T00:00:00.000Z",
+      end: "2025-03-03T00:00:00.000Z",
+      total: 12,
+      emojis: [{ emoji: "🚀", count: 5 }],
+      busiestDay: { day: "tuesday", count: 4 },
+    },
+    errors: [
+      "400 invalid_week",
+      "404 invalid_actor",
+      "404 no_statuses",
+      "422 week_out_of_reach",
+      "502 upstream_failed",
+    ],
+  },
+  {
+    method: "GET",
+    path: "/api/tags/trending",
+    summary:
+      "the most-used #tags in statuses from the last 7 days, refreshed every few minutes. " +
+      "complete is false when the week was too busy to scan in full.",
+    auth: "none",
+    params: [{ name: "limit", in: "query", description: "how many tags, 1–50, default 20" }],
+    returns: "application/json",
+    example: {
+      since: "2025-02-22T12:00:00.000Z",
+      tags: [
+        { tag: "focus", count: 42 },
+        { tag: "onvacation", count: 17 },
+      ],
+      complete: true,
+    },
+    errors: ["400 invalid_limit", "502 upstream_failed"],
+  },
+  {
+    method: "POST",
+    path: "/api/statuses/batch",
+    summary: "current statuses for up to 50 actors, keyed by the identifier as sent",
+    auth: "none",
+    params: [
+      { name: "actors", in: "body", description: "array of handles and/or DIDs", required: true },
+    ],
+    returns: "application/json",
+    example: { statuses: { "alice.bsky.social": CURRENT_STATUS_EXAMPLE } },
+    errors: [
+      "400 malformed body, too many actors or invalid identifiers",
+      "429 more than 60 requests a minute",
+      "502 status index unavailable",
+    ],
+  },
+  {
+    method: "POST",
+    path: "/api/resolve-handles",
+    summary: "DID → verified handle for up to 100 DIDs; unknown DIDs map to null",
+    auth: "none",
+    params: [{ name: "dids", in: "body", description: "array of DIDs", required: true }],
+    returns: "application/json",
+    example: { handles: { "did:plc:abc123": "alice.bsky.social", "did:plc:gone": null } },
+    errors: [
+      "400 malformed body, too many DIDs or invalid DIDs",
+      "429 more than 20 requests a minute",
+    ],
+  },
+  {
+    method: "POST",
+    path: "/api/resolve-dids",
+    summary: "handle → DID for up to 100 handles; unknown handles map to null",
+    auth: "none",
+    params: [{ name: "handles", in: "body", description: "array of handles", required: true }],
+    returns: "application/json",
+    example: { dids: { "alice.bsky.social": "did:plc:abc123", "nobody.example.com": null } },
+    errors: [
+      "400 malformed body, too many handles or invalid handles",
+      "429 more than 20 requests a minute",
+    ],
+  },
+  {
+    method: "GET",
+    path: "/oembed",
+    summary: "oEmbed for profile (/@handle, /profile/did) and share (/status/did/rkey) urls",
+    auth: "none",
+    params: [
+      {
+        name: "url",
+        in: "query",
+        description: "a profile or share url on this site",
+        required: true,
+      },
+      { name: "format", in: "query", description: "only json is supported" },
+      { name: "maxwidth", in: "query", description: "iframe width cap, default 400" },
+      { name: "maxheight", in: "query", description: "iframe height cap, default 120" },
+    ],
+    returns: "application/json",
+    example: {
+      version: "1.0",
+      type: "rich",
+      provider_name: "status",
+      title: "@alice.bsky.social's status",
+      html: '<iframe src="https://status.zzstoatzz.io/embed/alice.bsky.social" width="400" height="120" …></iframe>',
+      width: 400,
+      height: 120,
+    },
+    errors: [
+      "400 missing or invalid url",
+      "404 not a page on this site, or unknown account",
+      "501 format other than json",
+    ],
+  },
+  {
+    method: "GET",
+    path: "/embed/{handle}",
+    summary: "a script-free card with the actor's current status, for iframes",
+    auth: "none",
+    params: [HANDLE_PARAM, THEME_PARAM],
+    returns: "text/html",
+  },
+  {
+    method: "GET",
+    path: "/embed/status/{did}/{rkey}",
+    summary: "a script-free card pinned to one status, for iframes",
+    auth: "none",
+    params: [...STATUS_PARAMS, THEME_PARAM],
+    returns: "text/html",
+  },
+  {
+    method: "GET",
+    path: "/api/docs",
+    summary: "this list, as json",
+    auth: "none",
+    params: [],
+    returns: "application/json",
+  },
+];
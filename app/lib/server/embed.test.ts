@@ -0,0 +1,43 @@
+import { describe, expect, it } from "vite-plus/test";
+import { embedTarget } from "./embed";
+
+const origin = "https://status.example.com";
+const target = (url: string) => embedTarget(new URL(url), origin);
+
+describe("embedTarget", () => {
+  it("maps profile urls to the actor's embed", () => {
+    expect(target(`${origin}/@alice.bsky.social`)).toEqual({
+      path: "alice.bsky.social",
+      actor: "alice.bsky.social",
+    });
+    expect(target(`${origin}/profile/did:plc:abc123`)).toEqual({
+      path: "did%3Aplc%3Aabc123",
+      actor: "did:plc:abc123",
+    });
+  });
+
+  it("maps share urls to that status's embed", () => {
+    expect(target(`${origin}/status/did:plc:abc123/3kabc`)).toEqual({
+      path: "status/did%3Aplc%3Aabc123/3kabc",
+      actor: "did:plc:abc123",
+    });
+  });
+
+  it("ignores query strings, fragments and a trailing slash", () => {
+    expect(target(`${origin}/@alice.bsky.social/?ref=x#top`)?.actor).toBe("alice.bsky.social");
+  });
+
+  it("rejects urls on other origins", () => {
+    expect(target("https://evil.example/@alice.bsky.social")).toBeNull();
+    expect(target("http://status.example.com/@alice.bsky.social")).toBeNull();
+    expect(target("https://status.example.com:8443/@alice.bsky.social")).toBeNull();
+  });
+
+  it("rejects other pages and malformed encoding", () => {
+    expect(target(`${origin}/`)).toBeNull();
+    expect(target(`${origin}/tag/focus`)).toBeNull();
+    expect(target(`${origin}/@alice.bsky.social/wrapped/2025-W02`)).toBeNull();
+    expect(target(`${origin}/status/did:plc:abc123`)).toBeNull();
+    expect(target(`${origin}/@%E0%A4%A`)).toBeNull();
+  });
+});
@@ -0,0 +1,36 @@
+// embeds render inside other people's pages: no scripts at all, only our own styles and
+// https images, and any site may frame them
+export const EMBED_CSP =
+  "default-src 'none'; style-src 'self' 'unsafe-inline'; img-src https: data:; frame-ancestors *";
+
+export interface EmbedTarget {
+  // path under /embed/
+  path: string;
+  // handle or DID, as it appeared in the url
+  actor: string;
+}
+
+// which embed a page on this instance maps to: /@{handle} and /profile/{did} embed the
+// actor's current status, /status/{did}/{rkey} embeds that one status. null for pages
+// on other origins and for anything else here.
+export function embedTarget(target: URL, origin: string): EmbedTarget | null {
+  if (target.origin !== new URL(origin).origin) return null;
+
+  let parts: string[];
+  try {
+    parts = target.pathname.split("/").filter(Boolean).map(decodeURIComponent);
+  } catch {
+    return null; // malformed percent-encoding
+  }
+  const encoded = parts.map(encodeURIComponent);
+  if (parts.length === 1 && parts[0].startsWith("@")) {
+    return { path: encodeURIComponent(parts[0].slice(1)), actor: parts[0].slice(1) };
+  }
+  if (parts.length === 2 && parts[0] === "profile") {
+    return { path: encoded[1], actor: parts[1] };
+  }
+  if (parts.length === 3 && parts[0] === "status") {
+    return { path: `status/${encoded[1]}/${encoded[2]}`, actor: parts[1] };
+  }
+  return null;
+}
@@ -0,0 +1,82 @@
+import { afterEach, describe, expect, it, vi } from "vite-plus/test";
+
+vi.mock("$hatk/client", () => ({ callXrpc: vi.fn() }));
+
+import { callXrpc } from "$hatk/client";
+import { displayHandle, normalizeHandle, resolveDid, resolveHandle } from "./identity";
+
+const callXrpcMock = vi.mocked(callXrpc);
+
+describe("normalizeHandle", () => {
+  it("folds case and punycode into one form", () => {
+    expect(normalizeHandle("@Bücher.Example.com")).toBe("xn--bcher-kva.example.com");
+    expect(displayHandle("xn--bcher-kva.example.com")).toBe("bücher.example.com");
+  });
+
+  it("rejects input the url parser would reinterpret", () => {
+    expect(normalizeHandle("ä@alice.bsky.social")).toBeNull();
+    expect(normalizeHandle("bücher.de/x")).toBeNull();
+  });
+});
+
+describe("resolveHandle", () => {
+  afterEach(() => {
+    callXrpcMock.mockReset();
+    vi.unstubAllGlobals();
+    vi.useRealTimers();
+  });
+
+  it("serves case variants from one cache entry", async () => {
+    callXrpcMock.mockResolvedValue({ items: [{ did: "did:plc:alice" }] });
+    expect(await resolveHandle(normalizeHandle("Alice.Test")!)).toBe("did:plc:alice");
+    expect(await resolveHandle(normalizeHandle("alice.TEST")!)).toBe("did:plc:alice");
+    expect(callXrpcMock).toHaveBeenCalledTimes(1);
+  });
+
+  it("doesn't ask the resolver again for a miss until the ttl runs out", async () => {
+    vi.useFakeTimers();
+    vi.setSystemTime(new Date("2025-03-01T12:00:00.000Z"));
+    callXrpcMock.mockResolvedValue({ items: [] });
+    const resolver = vi.fn(async () => new Response(null, { status: 400 }));
+    vi.stubGlobal("fetch", resolver);
+
+    expect(await resolveHandle("made-up.test")).toBeNull();
+    expect(resolver).toHaveBeenCalledTimes(1);
+
+    vi.advanceTimersByTime(9 * 60 * 1000);
+    expect(await resolveHandle("made-up.test")).toBeNull();
+    expect(resolver).toHaveBeenCalledTimes(1);
+    expect(callXrpcMock).toHaveBeenCalledTimes(1);
+
+    vi.advanceTimersByTime(2 * 60 * 1000);
+    expect(await resolveHandle("made-up.test")).toBeNull();
+    expect(resolver).toHaveBeenCalledTimes(2);
+  });
+});
+
+describe("resolveDid", () => {
+  afterEach(() => {
+    callXrpcMock.mockReset();
+    vi.unstubAllGlobals();
+  });
+
+  const didDocument = (did: string, handle: string) =>
+    new Response(JSON.stringify({ id: did, alsoKnownAs: [`at://${handle}`] }));
+
+  it("keeps a claimed handle only when it resolves back to the DID", async () => {
+    callXrpcMock.mockResolvedValue({ items: [] });
+    vi.stubGlobal(
+      "fetch",
+      vi.fn(async (url: string) => {
+        if (url.includes("plc.directory/did:plc:honest")) return didDocument("did:plc:honest", "honest.test");
+        if (url.includes("plc.directory/did:plc:liar")) return didDocument("did:plc:liar", "honest.test");
+        if (url.includes("resolveHandle")) return Response.json({ did: "did:plc:honest" });
+        return new Response(null, { status: 404 });
+      }),
+    );
+
+    expect(await resolveDid("did:plc:honest")).toEqual({ handle: "honest.test" });
+    expect(await resolveDid("did:plc:liar")).toEqual({ handle: null });
+    expect(await resolveDid("did:plc:nobody")).toBeNull();
+  });
+});
@@ -0,0 +1,147 @@
+import { domainToUnicode } from "node:url";
+import { callXrpc } from "$hatk/client";
+import { cleanHandle } from "$lib/utils/handle";
+
+export const DID = /^did:[a-z]+:[a-zA-Z0-9._:%-]+$/;
+export const HANDLE = /^([a-zA-Z0-9]([a-zA-Z0-9-]{0,61}[a-zA-Z0-9])?\.)+[a-zA-Z]([a-zA-Z0-9-]{0,61}[a-zA-Z0-9])?$/;
+
+// TLDs the atproto handle spec reserves; nothing under them can resolve
+const DISALLOWED_TLDS = [".alt", ".arpa", ".example", ".internal", ".invalid", ".local", ".localhost", ".onion"];
+
+const RESOLVER = "https://public.api.bsky.app/xrpc/com.atproto.identity.resolveHandle";
+const PLC_DIRECTORY = "https://plc.directory";
+const RESOLVE_TIMEOUT_MS = 3000;
+const FOUND_TTL_MS = 60 * 60 * 1000;
+const MISSING_TTL_MS = 10 * 60 * 1000;
+const MAX_CACHED = 10_000;
+
+// `/@Alice.bsky.social`, `/@@alice.bsky.social` and `/@alice.bsky.social` are all the
+// same handle, and so is the punycode of an internationalized one — this is the form
+// used for lookups and cache keys. returns null for anything that can't be a handle,
+// so junk like `/@admin` never reaches the resolver.
+export function normalizeHandle(input: string): string | null {
+  const handle = cleanHandle(input);
+  if (handle.length > 253 || !HANDLE.test(handle)) return null;
+  if (DISALLOWED_TLDS.some((tld) => handle.endsWith(tld))) return null;
+  return handle;
+}
+
+// routes that take either: DIDs pass through untouched, handles get normalized
+export function normalizeActor(input: string): string | null {
+  const actor = input.trim().replace(/^@/, "");
+  return DID.test(actor) ? actor : normalizeHandle(actor);
+}
+
+// for showing a normalized handle to people: punycode labels back in unicode
+export function displayHandle(handle: string): string {
+  return domainToUnicode(handle) || handle;
+}
+
+// cached lookups in both directions. misses are cached too (for less time), so crawlers
+// probing the same made-up handle don't trigger a lookup each time.
+type Cache<T> = Map<string, { value: T; expires: number }>;
+const handles: Cache<string | null> = new Map();
+const dids: Cache<DidInfo | null> = new Map();
+
+function cached<T>(cache: Cache<T>, key: string): { value: T } | undefined {
+  const entry = cache.get(key);
+  return entry && entry.expires > Date.now() ? entry : undefined;
+}
+
+function remember<T>(cache: Cache<T>, key: string, value: T) {
+  if (cache.size >= MAX_CACHED) {
+    cache.delete(cache.keys().next().value!);
+  }
+  cache.set(key, { value, expires: Date.now() + (value ? FOUND_TTL_MS : MISSING_TTL_MS) });
+}
+
+// handle → did, or null for handles that don't exist. expects a normalized handle.
+// throws if the resolver is unreachable, which is not cached — only a definite "no such
+// handle" is.
+export async function resolveHandle(handle: string): Promise<string | null> {
+  const hit = cached(handles, handle);
+  if (hit) return hit.value;
+
+  // anyone who has posted a status is already in the local index
+  const res = await callXrpc("dev.hatk.getFeed", { feed: "actor", actor: handle, limit: 1 });
+  let did: string | null = res.items?.[0]?.did ?? null;
+
+  if (!did) {
+    const lookup = await fetch(`${RESOLVER}?handle=${encodeURIComponent(handle)}`, {
+      signal: AbortSignal.timeout(RESOLVE_TIMEOUT_MS),
+    });
+    if (lookup.ok) {
+      did = (await lookup.json()).did ?? null;
+    } else if (lookup.status !== 400) {
+      // the resolver answers 400 for unknown handles; anything else is its problem, not ours
+      throw new Error(`handle resolver returned ${lookup.status}`);
+    }
+  }
+
+  remember(handles, handle, did);
+  return did;
+}
+
+// a DID that exists, and the handle it claims if that handle points back at it
+export interface DidInfo {
+  handle: string | null;
+}
+
+function didDocumentUrl(did: string): string | null {
+  if (did.startsWith("did:plc:")) return `${PLC_DIRECTORY}/${did}`;
+  if (did.startsWith("did:web:")) {
+    const [host, ...path] = did.slice("did:web:".length).split(":").map(decodeURIComponent);
+    return path.length > 0
+      ? `https://${host}/${path.join("/")}/did.json`
+      : `https://${host}/.well-known/did.json`;
+  }
+  return null;
+}
+
+// did → { handle }, or null for DIDs that don't exist. same caching and error rules as
+// resolveHandle. the handle in a DID document is only a claim, so it's kept only when
+// resolving it leads back to the same DID.
+export async function resolveDid(did: string): Promise<DidInfo | null> {
+  const hit = cached(dids, did);
+  if (hit) return hit.value;
+
+  // the local index only holds verified handles; it reports the DID itself when it has none
+  const res = await callXrpc("dev.hatk.getFeed", { feed: "actor", actor: did, limit: 1 });
+  const indexed = res.items?.[0]?.handle;
+  let info: DidInfo | null = indexed ? { handle: indexed !== did ? indexed : null } : null;
+
+  if (!info || !info.handle) {
+    const url = didDocumentUrl(did);
+    const lookup = url ? await fetch(url, { signal: AbortSignal.timeout(RESOLVE_TIMEOUT_MS) }) : null;
+    if (!lookup || lookup.status === 404 || lookup.status === 410) {
+      // no document: keep whatever the index knew (an indexed DID still exists)
+    } else if (!lookup.ok) {
+      throw new Error(`DID document lookup returned ${lookup.status}`);
+    } else {
+      const doc = (await lookup.json()) as { alsoKnownAs?: string[] };
+      const claimed = doc.alsoKnownAs?.find((aka) => aka.startsWith("at://"))?.slice("at://".length);
+      const handle = claimed ? normalizeHandle(claimed) : null;
+      info = { handle: handle && (await resolveHandle(handle)) === did ? handle : null };
+    }
+  }
+
+  remember(dids, did, info);
+  return info;
+}
+
+// `handle` is null for a DID without a verified handle
+export interface Account {
+  did: string;
+  handle: string | null;
+}
+
+// a normalized actor of either kind → the account behind it, or null when there's no
+// such account
+export async function resolveActor(actor: string): Promise<Account | null> {
+  if (DID.test(actor)) {
+    const info = await resolveDid(actor);
+    return info && { did: actor, handle: info.handle };
+  }
+  const did = await resolveHandle(actor);
+  return did ? { did, handle: actor } : null;
+}
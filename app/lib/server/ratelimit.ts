@@ -0,0 +1,21 @@
+const MAX_TRACKED = 10_000;
+
+// fixed-window request counting per key (usually route + client address). in memory,
+// so each process counts on its own — enough to keep one client from fanning out
+// thousands of outbound lookups.
+const windows = new Map<string, { count: number; resetAt: number }>();
+
+// true when this request goes over `limit` for the current window
+export function rateLimited(key: string, limit: number, windowMs: number): boolean {
+  const now = Date.now();
+  let window = windows.get(key);
+  if (!window || window.resetAt <= now) {
+    if (windows.size >= MAX_TRACKED) {
+      windows.delete(windows.keys().next().value!);
+    }
+    window = { count: 0, resetAt: now + windowMs };
+    windows.set(key, window);
+  }
+  window.count++;
+  return window.count > limit;
+}
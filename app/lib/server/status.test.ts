@@ -0,0 +1,192 @@
+import { afterEach, describe, expect, it, vi } from "vite-plus/test";
+
+vi.mock("$hatk/client", () => ({ callXrpc: vi.fn() }));
+
+import { callXrpc } from "$hatk/client";
+import { handleStatusResponse, loadCurrentStatuses, loadShareView } from "./status";
+
+const callXrpcMock = vi.mocked(callXrpc);
+const did = "did:plc:alice";
+const uri = `at://${did}/io.zzstoatzz.status.record/3kabc`;
+const view = (emoji: string) => ({
+  uri,
+  cid: "bafy",
+  did,
+  handle: "alice.test",
+  emoji,
+  text: "shipping #status",
+  tags: ["status"],
+  createdAt: "2025-03-01T12:00:00.000Z",
+  indexedAt: "2025-03-01T12:00:02.000Z",
+  expired: false,
+});
+
+afterEach(() => {
+  callXrpcMock.mockReset();
+});
+
+describe("loadShareView", () => {
+  it("returns the hydrated statusView for the record", async () => {
+    callXrpcMock.mockResolvedValue({ items: [view("🚀")] } as never);
+    const fetchFn = vi.fn();
+
+    const result = await loadShareView(did, "3kabc", fetchFn as never);
+
+    expect(callXrpcMock).toHaveBeenCalledWith("dev.hatk.getFeed", {
+      feed: "status",
+      uri,
+      limit: 1,
+    });
+    expect(result).toEqual({ did, rkey: "3kabc", uri, status: view("🚀"), emojiUrl: null });
+    expect(result.status?.indexedAt).toBe("2025-03-01T12:00:02.000Z");
+    expect(fetchFn).not.toHaveBeenCalled();
+  });
+
+  it("returns a null status when the record doesn't exist", async () => {
+    callXrpcMock.mockResolvedValue({ items: [] } as never);
+
+    const result = await loadShareView(did, "missing", vi.fn() as never);
+
+    expect(result.status).toBeNull();
+    expect(result.emojiUrl).toBeNull();
+    expect(result.uri).toBe(`at://${did}/io.zzstoatzz.status.record/missing`);
+  });
+
+  it("resolves an image url for custom emoji", async () => {
+    callXrpcMock.mockResolvedValue({ items: [view("custom:bufo-party")] } as never);
+    const fetchFn = vi.fn().mockResolvedValue({ ok: true });
+
+    const result = await loadShareView(did, "3kabc", fetchFn as never);
+
+    expect(fetchFn).toHaveBeenCalled();
+    expect(result.emojiUrl).toContain("bufo-party");
+  });
+
+  it("rethrows appview failures instead of reporting not found", async () => {
+    callXrpcMock.mockRejectedValue(new Error("502 from appview"));
+
+    await expect(loadShareView(did, "3kabc", vi.fn() as never)).rejects.toThrow("502");
+  });
+});
+
+describe("loadCurrentStatuses", () => {
+  afterEach(() => {
+    vi.unstubAllGlobals();
+  });
+
+  // the actor feed answers handle lookups for indexed accounts; the current feed answers
+  // with whichever of the requested DIDs have a live status
+  const feeds = (handles: Record<string, string>, live: string[]) =>
+    callXrpcMock.mockImplementation((async (_method: string, params: Record<string, unknown>) => {
+      if (params.feed === "actor") {
+        const found = handles[params.actor as string];
+        return { items: found ? [{ did: found }] : [] };
+      }
+      const requested = String(params.actors).split(",");
+      return {
+        items: live
+          .filter((d) => requested.includes(d))
+          .map((d) => ({ ...view("🚀"), did: d, uri: `at://${d}/io.zzstoatzz.status.record/3k` })),
+      };
+    }) as never);
+
+  it("resolves handles then fetches every status in one current-feed query", async () => {
+    feeds({ "carol.test": "did:plc:carol" }, ["did:plc:carol", "did:plc:dave"]);
+    vi.stubGlobal("fetch", vi.fn().mockResolvedValue(new Response("", { status: 400 })));
+
+    const result = await loadCurrentStatuses([
+      "carol.test",
+      "did:plc:dave",
+      "did:plc:erin",
+      "nobody.test",
+    ]);
+
+    const currentCalls = callXrpcMock.mock.calls.filter(
+      ([, p]) => (p as { feed: string }).feed === "current",
+    );
+    expect(currentCalls).toEqual([
+      [
+        "dev.hatk.getFeed",
+        { feed: "current", actors: "did:plc:carol,did:plc:dave,did:plc:erin", limit: 3 },
+      ],
+    ]);
+    expect(result["carol.test"]).toMatchObject({
+      status: "known",
+      did: "did:plc:carol",
+      emoji: "🚀",
+    });
+    expect(result["did:plc:dave"]).toMatchObject({ status: "known", did: "did:plc:dave" });
+    expect(result["did:plc:erin"]).toEqual({ status: "unknown" });
+    expect(result["nobody.test"]).toEqual({ status: "unknown" });
+  });
+
+  it("reads unresolvable handles as unknown without querying the feed", async () => {
+    feeds({}, []);
+    vi.stubGlobal("fetch", vi.fn().mockRejectedValue(new Error("timeout")));
+
+    const result = await loadCurrentStatuses(["ghost.test"]);
+
+    expect(result).toEqual({ "ghost.test": { status: "unknown" } });
+    expect(callXrpcMock.mock.calls.map(([, p]) => (p as { feed: string }).feed)).toEqual(["actor"]);
+  });
+
+  it("rethrows when the current feed fails", async () => {
+    callXrpcMock.mockRejectedValue(new Error("502 from appview"));
+
+    await expect(loadCurrentStatuses(["did:plc:frank"])).rejects.toThrow("502");
+  });
+});
+
+describe("handleStatusResponse", () => {
+  afterEach(() => {
+    vi.unstubAllGlobals();
+  });
+
+  // plc.directory knows did:plc:quiet, and nothing else
+  const directory = vi.fn(async (url: string) =>
+    url.endsWith("/did:plc:quiet")
+      ? Response.json({ id: "did:plc:quiet", alsoKnownAs: [] })
+      : new Response(null, { status: 404 }),
+  );
+
+  it("answers unknown for a real DID with no status", async () => {
+    callXrpcMock.mockResolvedValue({ items: [] } as never);
+    vi.stubGlobal("fetch", directory);
+
+    const res = await handleStatusResponse("did:plc:quiet");
+
+    expect(res.status).toBe(200);
+    expect(await res.json()).toEqual({ status: "unknown", did: "did:plc:quiet" });
+  });
+
+  it("404s a well-formed DID that doesn't exist", async () => {
+    callXrpcMock.mockResolvedValue({ items: [] } as never);
+    vi.stubGlobal("fetch", directory);
+
+    const res = await handleStatusResponse("did:plc:madeup");
+
+    expect(res.status).toBe(404);
+    expect(await res.json()).toEqual({ error: "did_not_found", did: "did:plc:madeup" });
+  });
+
+  it("takes the index's word for a DID whose status expired", async () => {
+    callXrpcMock.mockResolvedValue({ items: [{ ...view("🚀"), expired: true }] } as never);
+    const lookup = vi.fn();
+    vi.stubGlobal("fetch", lookup);
+
+    const res = await handleStatusResponse(did);
+
+    expect(res.status).toBe(200);
+    expect(await res.json()).toEqual({ status: "unknown", did });
+    expect(lookup).not.toHaveBeenCalled();
+  });
+
+  it("answers 502 when the DID can't be checked", async () => {
+    callXrpcMock.mockResolvedValue({ items: [] } as never);
+    vi.stubGlobal("fetch", vi.fn(async () => new Response(null, { status: 503 })));
+
+    const res = await handleStatusResponse("did:plc:flaky");
+
+    expect(res.status).toBe(502);
+  });
+});
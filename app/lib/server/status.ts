@@ -0,0 +1,152 @@
+import { json } from "@sveltejs/kit";
+import { callXrpc } from "$hatk/client";
+import { mapConcurrent } from "$lib/server/concurrency";
+import { DID, normalizeHandle, resolveDid, resolveHandle } from "$lib/server/identity";
+import { isCustomEmoji, customEmojiName, resolveBufoUrl } from "$lib/utils/emoji";
+import { expiresInSeconds } from "$lib/utils/expiry";
+
+// a hydrated statusView from the feeds, narrowed to the fields read here
+export interface StatusView {
+  uri: string;
+  did: string;
+  handle: string;
+  emoji: string;
+  text?: string;
+  tags?: string[];
+  mentions?: string[];
+  context?: string;
+  via?: string;
+  createdAt: string;
+  expires?: string;
+  expired: boolean;
+  indexedAt: string;
+}
+
+export interface ShareView {
+  did: string;
+  rkey: string;
+  uri: string;
+  status: StatusView | null;
+  emojiUrl: string | null;
+}
+
+// single loader behind the share page, its json twin and the per-status embed, so they
+// can't diverge. `status` is null only when there's no such record (or it was taken
+// down); appview failures throw so callers can answer 502 instead of "not found".
+export async function loadShareView(
+  did: string,
+  rkey: string,
+  fetchFn: typeof fetch,
+): Promise<ShareView> {
+  const uri = `at://${did}/io.zzstoatzz.status.record/${rkey}`;
+
+  const res = await callXrpc("dev.hatk.getFeed", { feed: "status", uri, limit: 1 });
+  const status = (res.items?.[0] as StatusView | undefined) ?? null;
+  if (!status) return { did, rkey, uri, status: null, emojiUrl: null };
+
+  const emojiUrl = isCustomEmoji(status.emoji)
+    ? await resolveBufoUrl(customEmojiName(status.emoji), fetchFn)
+    : null;
+  return { did, rkey, uri, status, emojiUrl };
+}
+
+export type CurrentStatus =
+  | {
+      status: "known";
+      uri: string;
+      did: string;
+      handle: string;
+      emoji: string;
+      text?: string;
+      context?: string;
+      since: string;
+      expires?: string;
+      expiresInSeconds?: number;
+      indexedAt: string;
+    }
+  | { status: "unknown" };
+
+function knownStatus(item: StatusView): CurrentStatus {
+  return {
+    status: "known",
+    uri: item.uri,
+    did: item.did,
+    handle: item.handle,
+    emoji: item.emoji,
+    text: item.text,
+    context: item.context,
+    since: item.createdAt,
+    expires: item.expires,
+    expiresInSeconds: expiresInSeconds(item.expires),
+    indexedAt: item.indexedAt,
+  };
+}
+
+// an actor's live status: their newest record, unless it has already expired.
+// `actor` may be a handle or a DID — the actor feed resolves either.
+export async function loadCurrentStatus(actor: string): Promise<CurrentStatus> {
+  const res = await callXrpc("dev.hatk.getFeed", { feed: "actor", actor, limit: 1 });
+  const latest = res.items?.[0] as StatusView | undefined;
+  if (!latest || latest.expired) return { status: "unknown" };
+  return knownStatus(latest);
+}
+
+const RESOLVE_CONCURRENCY = 8;
+
+// current statuses for many actors at once, keyed by each actor exactly as given.
+// `actors` must already be normalized (see normalizeActor). handles go through the
+// cached resolver; then one current-feed query covers every DID. unknown handles, and
+// handles the resolver can't answer for right now, read as "unknown"; a failing feed
+// query throws.
+export async function loadCurrentStatuses(
+  actors: string[],
+): Promise<Record<string, CurrentStatus>> {
+  const dids = await mapConcurrent(actors, RESOLVE_CONCURRENCY, (actor) =>
+    DID.test(actor) ? Promise.resolve(actor) : resolveHandle(actor).catch(() => null),
+  );
+
+  const wanted = [...new Set(dids.filter((did): did is string => !!did))];
+  const byDid = new Map<string, CurrentStatus>();
+  if (wanted.length > 0) {
+    const res = await callXrpc("dev.hatk.getFeed", {
+      feed: "current",
+      actors: wanted.join(","),
+      limit: wanted.length,
+    });
+    for (const item of (res.items ?? []) as StatusView[]) byDid.set(item.did, knownStatus(item));
+  }
+
+  return Object.fromEntries(
+    actors.map((actor, i) => [actor, byDid.get(dids[i] ?? "") ?? { status: "unknown" }]),
+  );
+}
+
+// the JSON for /@{handle}: 404 when there's no such user, otherwise their current status
+// (or "unknown" if they have none right now), always with the resolved DID attached.
+// a well-formed DID with no status is looked up too, so made-up ones 404 as well.
+export async function handleStatusResponse(input: string): Promise<Response> {
+  const isDid = DID.test(input);
+  const handle = isDid ? null : normalizeHandle(input);
+  if (!isDid && !handle) {
+    return json({ error: "handle_not_found", handle: input }, { status: 404 });
+  }
+
+  let current: CurrentStatus;
+  let did: string | null;
+  try {
+    did = handle ? await resolveHandle(handle) : input;
+    if (!did) return json({ error: "handle_not_found", handle }, { status: 404 });
+    current = await loadCurrentStatus(did);
+    if (isDid && current.status === "unknown" && !(await resolveDid(did))) {
+      return json({ error: "did_not_found", did }, { status: 404 });
+    }
+  } catch {
+    return json({ error: "upstream_failed" }, { status: 502 });
+  }
+
+  // no Last-Modified/ETag here: expiresInSeconds counts down, so a 304 would hand
+  // back a stale body
+  return json(current.status === "known" ? current : { ...current, did }, {
+    headers: { "cache-control": "public, max-age=30" },
+  });
+}
@@ -0,0 +1,81 @@
+import { beforeEach, describe, expect, it, vi } from "vite-plus/test";
+
+vi.mock("$hatk/client", () => ({ callXrpc: vi.fn() }));
+
+import { callXrpc } from "$hatk/client";
+import { countTags, loadTrendingTags } from "./trending";
+
+const callXrpcMock = vi.mocked(callXrpc);
+const DAY = 24 * 60 * 60 * 1000;
+const at = (now: number, daysAgo: number, tags?: string[]) => ({
+  createdAt: new Date(now - daysAgo * DAY).toISOString(),
+  tags,
+});
+
+beforeEach(() => {
+  callXrpcMock.mockReset();
+});
+
+describe("countTags", () => {
+  it("counts statuses per tag, most used first, ties alphabetical", () => {
+    expect(
+      countTags([{ tags: ["focus", "music"] }, { tags: ["music"] }, {}, { tags: ["art"] }]),
+    ).toEqual([
+      { tag: "music", count: 2 },
+      { tag: "art", count: 1 },
+      { tag: "focus", count: 1 },
+    ]);
+  });
+});
+
+describe("loadTrendingTags", () => {
+  it("walks the recent feed back to the start of the window", async () => {
+    const now = Date.parse("2025-03-08T12:00:00Z");
+    const pages = [
+      { items: [at(now, 0, ["focus"]), at(now, 2, ["focus", "art"])], cursor: "p2" },
+      { items: [at(now, 6, ["art"]), at(now, 8, ["old"])], cursor: "p3" },
+    ];
+    callXrpcMock.mockResolvedValueOnce(pages[0] as never).mockResolvedValueOnce(pages[1] as never);
+
+    const trending = await loadTrendingTags(now);
+
+    expect(callXrpcMock).toHaveBeenCalledTimes(2);
+    expect(callXrpcMock).toHaveBeenLastCalledWith("dev.hatk.getFeed", {
+      feed: "recent",
+      includeExpired: true,
+      limit: 100,
+      cursor: "p2",
+    });
+    expect(trending).toEqual({
+      since: "2025-03-01T12:00:00.000Z",
+      tags: [
+        { tag: "art", count: 2 },
+        { tag: "focus", count: 2 },
+      ],
+      complete: true,
+    });
+  });
+
+  it("serves repeat calls from cache for a few minutes", async () => {
+    const now = Date.parse("2025-04-08T12:00:00Z");
+    callXrpcMock.mockResolvedValue({ items: [at(now, 1, ["focus"])] } as never);
+
+    await loadTrendingTags(now);
+    await loadTrendingTags(now + 60_000);
+    expect(callXrpcMock).toHaveBeenCalledTimes(1);
+
+    await loadTrendingTags(now + 6 * 60_000);
+    expect(callXrpcMock).toHaveBeenCalledTimes(2);
+  });
+
+  it("reports a busy week it couldn't scan in full", async () => {
+    const now = Date.parse("2025-05-08T12:00:00Z");
+    callXrpcMock.mockResolvedValue({ items: [at(now, 0, ["focus"])], cursor: "more" } as never);
+
+    const trending = await loadTrendingTags(now);
+
+    expect(callXrpcMock).toHaveBeenCalledTimes(50);
+    expect(trending.complete).toBe(false);
+    expect(trending.tags).toEqual([{ tag: "focus", count: 50 }]);
+  });
+});
@@ -0,0 +1,68 @@
+import { callXrpc } from "$hatk/client";
+
+const WINDOW_MS = 7 * 24 * 60 * 60 * 1000;
+const CACHE_MS = 5 * 60 * 1000;
+// 5000 statuses; past that the counts cover the newest part of the week
+const MAX_PAGES = 50;
+export const MAX_TRENDING = 50;
+
+export interface TagCount {
+  tag: string;
+  count: number;
+}
+
+export interface TrendingTags {
+  since: string;
+  tags: TagCount[];
+  // false when the week held more statuses than we're willing to scan
+  complete: boolean;
+}
+
+// each status counts once per tag (tags are already deduped per status); ties go
+// alphabetically so the order is stable between refreshes
+export function countTags(statuses: { tags?: string[] }[]): TagCount[] {
+  const counts = new Map<string, number>();
+  for (const status of statuses) {
+    for (const tag of status.tags ?? []) counts.set(tag, (counts.get(tag) ?? 0) + 1);
+  }
+  return [...counts.entries()]
+    .map(([tag, count]) => ({ tag, count }))
+    .sort((a, b) => b.count - a.count || a.tag.localeCompare(b.tag));
+}
+
+let cached: { value: TrendingTags; expires: number } | null = null;
+
+// the most-used tags over the last 7 days, counted from the recent feed (expired
+// statuses included — they were still posted this week). cached for a few minutes.
+export async function loadTrendingTags(now = Date.now()): Promise<TrendingTags> {
+  if (cached && cached.expires > now) return cached.value;
+
+  const since = now - WINDOW_MS;
+  const items: { createdAt: string; tags?: string[] }[] = [];
+  let cursor: string | undefined;
+  let complete = false;
+  for (let page = 0; page < MAX_PAGES; page++) {
+    const res = await callXrpc("dev.hatk.getFeed", {
+      feed: "recent",
+      includeExpired: true,
+      limit: 100,
+      ...(cursor ? { cursor } : {}),
+    });
+    items.push(...(res.items ?? []));
+    cursor = res.cursor;
+    const oldest = items[items.length - 1];
+    if (!cursor || (oldest && Date.parse(oldest.createdAt) < since)) {
+      complete = true;
+      break;
+    }
+  }
+
+  const week = items.filter((item) => Date.parse(item.createdAt) >= since);
+  const value = {
+    since: new Date(since).toISOString(),
+    tags: countTags(week).slice(0, MAX_TRENDING),
+    complete,
+  };
+  cached = { value, expires: now + CACHE_MS };
+  return value;
+}
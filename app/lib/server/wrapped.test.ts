@@ -0,0 +1,113 @@
+import { beforeEach, describe, expect, it, vi } from "vite-plus/test";
+
+vi.mock("$hatk/client", () => ({ callXrpc: vi.fn() }));
+
+import { callXrpc } from "$hatk/client";
+import { WeekOutOfReach, isoWeekRange, loadWrapped, summarizeWeek } from "./wrapped";
+
+const callXrpcMock = vi.mocked(callXrpc);
+const day = (range: ReturnType<typeof isoWeekRange>) => [
+  range!.start.toISOString().slice(0, 10),
+  range!.end.toISOString().slice(0, 10),
+];
+
+describe("isoWeekRange", () => {
+  it("starts week 1 on the monday of the week with january 4th", () => {
+    expect(day(isoWeekRange("2024-W01"))).toEqual(["2024-01-01", "2024-01-08"]);
+    expect(day(isoWeekRange("2021-W01"))).toEqual(["2021-01-04", "2021-01-11"]);
+  });
+
+  it("lets week 1 begin in the previous calendar year", () => {
+    expect(day(isoWeekRange("2026-W01"))).toEqual(["2025-12-29", "2026-01-05"]);
+  });
+
+  it("accepts week 53 only in years that have one", () => {
+    expect(day(isoWeekRange("2020-W53"))).toEqual(["2020-12-28", "2021-01-04"]);
+    expect(day(isoWeekRange("2015-W53"))).toEqual(["2015-12-28", "2016-01-04"]);
+    expect(isoWeekRange("2021-W53")).toBeNull();
+  });
+
+  it("rejects malformed labels", () => {
+    for (const label of ["2025-W00", "2025-W7", "2025W07", "2025-w07", "25-W07"]) {
+      expect(isoWeekRange(label)).toBeNull();
+    }
+  });
+});
+
+// 2025-W02: monday 2025-01-06 through sunday 2025-01-12
+const range = isoWeekRange("2025-W02")!;
+const status = (createdAt: string, emoji: string, expires?: string) => ({
+  uri: `at://did:plc:a/io.zzstoatzz.status.record/${createdAt}`,
+  did: "did:plc:a",
+  handle: "alice.test",
+  emoji,
+  createdAt,
+  expires,
+});
+
+describe("summarizeWeek", () => {
+  // newest first, as the actor feed returns them
+  const week = [
+    status("2025-01-10T09:00:00.000Z", "🎧"),
+    status("2025-01-08T15:00:00.000Z", "☕", "2025-01-08T16:00:00.000Z"),
+    status("2025-01-08T09:00:00.000Z", "🎧"),
+    status("2025-01-06T09:00:00.000Z", "🌴"),
+  ];
+
+  it("counts statuses, emoji and the busiest day", () => {
+    const summary = summarizeWeek(week, undefined, range, new Date("2025-02-01T00:00:00Z"));
+    expect(summary.total).toBe(4);
+    expect(summary.emojis).toEqual([
+      { emoji: "🎧", count: 2 },
+      { emoji: "☕", count: 1 },
+      { emoji: "🌴", count: 1 },
+    ]);
+    expect(summary.busiestDay).toEqual({ day: "wednesday", count: 2 });
+    expect(summary.first).toBe("2025-01-06T09:00:00.000Z");
+    expect(summary.last).toBe("2025-01-10T09:00:00.000Z");
+  });
+
+  it("ends each status at its expiry, its successor, or the end of the week", () => {
+    // the friday status runs into the following week but only counts up to sunday night
+    const summary = summarizeWeek(week, undefined, range, new Date("2025-02-01T00:00:00Z"));
+    expect(summary.longest?.emoji).toBe("🎧");
+    expect(summary.longest?.createdAt).toBe("2025-01-10T09:00:00.000Z");
+    expect(summary.longest?.durationMs).toBe(range.end.getTime() - Date.parse("2025-01-10T09:00:00.000Z"));
+
+    // a status set right after the week caps it sooner than the week's end
+    const after = status("2025-01-10T10:00:00.000Z", "😴");
+    const capped = summarizeWeek(week, after, range, new Date("2025-02-01T00:00:00Z"));
+    expect(capped.longest?.createdAt).toBe("2025-01-06T09:00:00.000Z");
+    expect(capped.longest?.durationMs).toBe(2 * 86_400_000);
+  });
+});
+
+describe("loadWrapped", () => {
+  beforeEach(() => callXrpcMock.mockReset());
+
+  it("refuses to summarize when the feed walk runs out before the week starts", async () => {
+    // every page is newer than the week and there's always another cursor
+    callXrpcMock.mockResolvedValue({
+      items: [status("2025-03-01T00:00:00.000Z", "🎧")],
+      cursor: "more",
+    });
+    await expect(loadWrapped("did:plc:busy", range)).rejects.toBeInstanceOf(WeekOutOfReach);
+  });
+
+  it("caches finished weeks per DID", async () => {
+    callXrpcMock.mockResolvedValue({
+      items: [status("2025-01-08T09:00:00.000Z", "🎧"), status("2024-12-30T09:00:00.000Z", "☕")],
+      cursor: undefined,
+    });
+    const first = await loadWrapped("did:plc:a", range);
+    const second = await loadWrapped("did:plc:a", range);
+    expect(first?.total).toBe(1);
+    expect(second).toBe(first);
+    expect(callXrpcMock).toHaveBeenCalledTimes(1);
+  });
+
+  it("is null for a week without statuses", async () => {
+    callXrpcMock.mockResolvedValue({ items: [status("2024-12-30T09:00:00.000Z", "☕")] });
+    expect(await loadWrapped("did:plc:quiet", range)).toBeNull();
+  });
+});
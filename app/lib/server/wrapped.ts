@@ -0,0 +1,164 @@
+import { callXrpc } from "$hatk/client";
+import { DID, resolveHandle } from "$lib/server/identity";
+
+const DAY_MS = 86_400_000;
+const MAX_PAGES = 50;
+const MAX_CACHED = 1_000;
+
+export interface WeekRange {
+  year: number;
+  week: number;
+  start: Date;
+  end: Date;
+}
+
+// ISO 8601 weeks: monday-start, week 1 is the week containing january 4th, and a week
+// belongs to the year its thursday falls in (so 2024-W01 starts 2024-01-01 but
+// 2026-W01 starts 2025-12-29). all in UTC.
+export function isoWeekRange(label: string): WeekRange | null {
+  const match = /^(\d{4})-W(\d{2})$/.exec(label);
+  if (!match) return null;
+  const year = Number(match[1]);
+  const week = Number(match[2]);
+  if (week < 1) return null;
+
+  const jan4 = Date.UTC(year, 0, 4);
+  const week1Monday = jan4 - ((new Date(jan4).getUTCDay() + 6) % 7) * DAY_MS;
+  const start = new Date(week1Monday + (week - 1) * 7 * DAY_MS);
+  const thursday = new Date(start.getTime() + 3 * DAY_MS);
+  if (thursday.getUTCFullYear() !== year) return null;
+
+  return { year, week, start, end: new Date(start.getTime() + 7 * DAY_MS) };
+}
+
+interface WeekStatus {
+  uri: string;
+  emoji: string;
+  text?: string;
+  createdAt: string;
+  expires?: string;
+}
+
+export interface WrappedSummary {
+  handle: string;
+  did: string;
+  week: string;
+  start: string;
+  end: string;
+  total: number;
+  emojis: { emoji: string; count: number }[];
+  longest: (WeekStatus & { durationMs: number }) | null;
+  busiestDay: { day: string; count: number } | null;
+  first: string;
+  last: string;
+}
+
+const WEEKDAYS = ["monday", "tuesday", "wednesday", "thursday", "friday", "saturday", "sunday"];
+
+// statuses are newest first, as the actor feed returns them; `after` is the status
+// that followed the newest one in the week (if any), which bounds its duration
+export function summarizeWeek(
+  statuses: WeekStatus[],
+  after: WeekStatus | undefined,
+  range: WeekRange,
+  now = new Date(),
+): Omit<WrappedSummary, "handle" | "did" | "week"> {
+  const emojiCounts = new Map<string, number>();
+  const dayCounts = new Array(7).fill(0);
+  let longest: WrappedSummary["longest"] = null;
+
+  statuses.forEach((status, i) => {
+    const created = new Date(status.createdAt);
+    emojiCounts.set(status.emoji, (emojiCounts.get(status.emoji) ?? 0) + 1);
+    dayCounts[(created.getUTCDay() + 6) % 7]++;
+
+    // a status runs until it expires or is replaced, counted only up to the end of the
+    // week (or now, for the current week) so a past week's summary never changes
+    const next = i === 0 ? after : statuses[i - 1];
+    const ends = [status.expires, next?.createdAt]
+      .filter((d): d is string => !!d)
+      .map((d) => new Date(d).getTime());
+    const endMs = Math.min(...ends, range.end.getTime(), now.getTime());
+    const durationMs = Math.max(0, endMs - created.getTime());
+    if (!longest || durationMs > longest.durationMs) longest = { ...status, durationMs };
+  });
+
+  const busiest = dayCounts.reduce((best, count, i) => (count > dayCounts[best] ? i : best), 0);
+
+  return {
+    start: range.start.toISOString(),
+    end: range.end.toISOString(),
+    total: statuses.length,
+    emojis: [...emojiCounts.entries()]
+      .map(([emoji, count]) => ({ emoji, count }))
+      .sort((a, b) => b.count - a.count),
+    longest,
+    busiestDay: statuses.length ? { day: WEEKDAYS[busiest], count: dayCounts[busiest] } : null,
+    first: statuses[statuses.length - 1]?.createdAt ?? "",
+    last: statuses[0]?.createdAt ?? "",
+  };
+}
+
+// the week is further back than we're willing to page through the actor feed
+export class WeekOutOfReach extends Error {}
+
+// past weeks can't change, so their summaries are cached, keyed by DID so a handle and
+// its DID share one entry
+const cache = new Map<string, WrappedSummary>();
+
+function remember(key: string, summary: WrappedSummary) {
+  if (cache.size >= MAX_CACHED) {
+    cache.delete(cache.keys().next().value!);
+  }
+  cache.set(key, summary);
+}
+
+// `actor` is a DID or a normalized handle. null when the actor doesn't exist or has no
+// statuses that week; throws WeekOutOfReach rather than summarizing part of a week.
+export async function loadWrapped(actor: string, range: WeekRange): Promise<WrappedSummary | null> {
+  const did = DID.test(actor) ? actor : await resolveHandle(actor);
+  if (!did) return null;
+
+  const week = `${range.year}-W${String(range.week).padStart(2, "0")}`;
+  const key = `${did}:${week}`;
+  const cached = cache.get(key);
+  if (cached) return cached;
+
+  // walk the actor feed back until we're past the start of the week, or out of history
+  const items: (WeekStatus & { did: string; handle: string })[] = [];
+  let cursor: string | undefined;
+  let complete = false;
+  for (let page = 0; page < MAX_PAGES; page++) {
+    const res = await callXrpc("dev.hatk.getFeed", {
+      feed: "actor",
+      actor: did,
+      limit: 100,
+      ...(cursor ? { cursor } : {}),
+    });
+    items.push(...(res.items ?? []));
+    cursor = res.cursor;
+    const oldest = items[items.length - 1];
+    if (!cursor || (oldest && new Date(oldest.createdAt) < range.start)) {
+      complete = true;
+      break;
+    }
+  }
+  if (!complete) throw new WeekOutOfReach(`${week} is more than ${MAX_PAGES * 100} statuses back`);
+
+  const inWeek = (s: WeekStatus) => {
+    const t = new Date(s.createdAt).getTime();
+    return t >= range.start.getTime() && t < range.end.getTime();
+  };
+  const firstIndex = items.findIndex(inWeek);
+  if (firstIndex === -1) return null;
+  const statuses = items.filter(inWeek);
+
+  const summary: WrappedSummary = {
+    handle: items[firstIndex].handle,
+    did,
+    week,
+    ...summarizeWeek(statuses, items[firstIndex - 1], range),
+  };
+  if (range.end.getTime() <= Date.now()) remember(key, summary);
+  return summary;
+}
@@ -0,0 +1,70 @@
+import { describe, expect, it } from "vite-plus/test";
+import { parseLinks } from "./emoji";
+import { extractMentions } from "../../../server/feeds/_mentions.ts";
+import { extractTags } from "../../../server/feeds/_tags.ts";
+
+const tagLinks = (html: string) =>
+  [...html.matchAll(/href="\/tag\/([^"]+)"/g)].map((m) => decodeURIComponent(m[1]));
+
+const mentionLinks = (html: string) => [...html.matchAll(/href="\/@([^"]+)"/g)].map((m) => m[1]);
+
+describe("parseLinks", () => {
+  it("escapes html outside and inside links", () => {
+    expect(parseLinks(`<b>hi</b> & "bye"`)).toBe("&lt;b&gt;hi&lt;/b&gt; &amp; &quot;bye&quot;");
+    expect(parseLinks(`[<i>docs</i>](https://example.com/?a=1&b="2")`)).toBe(
+      '<a href="https://example.com/?a=1&amp;b=&quot;2&quot;" target="_blank" rel="noopener">&lt;i&gt;docs&lt;/i&gt;</a>',
+    );
+  });
+
+  it("leaves text that only looks like an entity alone", () => {
+    expect(parseLinks("a&#foo and &#39; and &amp;")).toBe("a&amp;#foo and &amp;#39; and &amp;amp;");
+  });
+
+  it("links the same tags the server extracts", () => {
+    for (const text of [
+      "a&#foo b",
+      "x.com/#top",
+      "foo#bar ##double",
+      "🎧#music (#Focus) #2024 #été",
+      '"#quoted" <#angled> & #amp',
+    ]) {
+      expect(tagLinks(parseLinks(text))).toEqual(extractTags(text));
+    }
+  });
+
+  it("links the same mentions the server extracts", () => {
+    for (const text of [
+      "mail bob@example.com or x.com/@bob.test",
+      "pairing with @Alice.bsky.social. then (@bob.test), @carol.dev!",
+      "[ask @bob.test](https://example.com) or @carol.dev",
+      '"@quoted.test" and &quot;@entity.test',
+      "@bob.test_x @bob",
+    ]) {
+      expect(mentionLinks(parseLinks(text))).toEqual(extractMentions(text));
+    }
+  });
+
+  it("keeps a mention inside markdown link text part of the link", () => {
+    expect(parseLinks("[ask @bob.test](https://example.com)")).toBe(
+      '<a href="https://example.com" target="_blank" rel="noopener">ask @bob.test</a>',
+    );
+  });
+
+  it("links a quoted mention and escapes the quote around it", () => {
+    expect(parseLinks('"@bob.test"')).toBe('&quot;<a href="/@bob.test" class="mention">@bob.test</a>&quot;');
+  });
+
+  it("rejects non-http markdown links", () => {
+    expect(parseLinks("[x](javascript:alert(1))")).toBe("[x](javascript:alert(1))");
+  });
+
+  it("opens every link in a new tab when asked, for embeds", () => {
+    const html = parseLinks("hi @alice.test #focus [docs](https://example.com)", { newTab: true });
+    expect(html.match(/<a /g)).toHaveLength(3);
+    expect(html.match(/target="_blank" rel="noopener"/g)).toHaveLength(3);
+  });
+
+  it("keeps mentions and tags in the same tab by default", () => {
+    expect(parseLinks("@alice.test #focus")).not.toContain("_blank");
+  });
+});
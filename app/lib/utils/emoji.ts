@@ -59,22 +59,56 @@ export async function resolveBufoUrl(
   return candidates[0];
 }
 
-export function parseLinks(text: string): string {
-  if (!text) return "";
-  const escaped = text
+const MARKDOWN_LINK = String.raw`\[([^\]]+)\]\(([^)]+)\)`;
+// @handle mentions, tokenized the same way as server/feeds/_mentions.ts. a mention must
+// open the text or follow whitespace, "(" or a quote, so emails (bob@example.com) and url
+// paths (x.com/@bob) don't match, and handles are domains so they need at least one dot.
+// trailing punctuation falls off because a tld must start with a letter.
+const MENTION = String.raw`(^|[\s("'“‘])@((?:[a-zA-Z0-9](?:[a-zA-Z0-9-]{0,61}[a-zA-Z0-9])?\.)+[a-zA-Z](?:[a-zA-Z0-9-]{0,61}[a-zA-Z0-9])?)(?![\w-])`;
+// #hashtags, tokenized the same way as server/feeds/_tags.ts (digits-only tags are
+// skipped below): not after a letter, digit, `&`, `/` or `#`, so `foo#bar`, `a&#39;`
+// and url fragments aren't tags.
+const HASHTAG = String.raw`(?<![\p{L}\p{M}\p{N}_&/#])#([\p{L}\p{M}\p{N}_]{1,64})(?![\p{L}\p{M}\p{N}_])`;
+// one pass over all three, so a mention or tag inside [link text](url) stays part of that link
+const LINK_TOKENS = new RegExp(`${MARKDOWN_LINK}|${MENTION}|${HASHTAG}`, "gu");
+
+function escapeHtml(text: string): string {
+  return text
     .replace(/&/g, "&amp;")
     .replace(/</g, "&lt;")
     .replace(/>/g, "&gt;")
     .replace(/"/g, "&quot;");
-  return escaped.replace(
-    /\[([^\]]+)\]\(([^)]+)\)/g,
-    (_match: string, linkText: string, url: string) => {
-      if (url.startsWith("http://") || url.startsWith("https://")) {
-        return `<a href="${url}" target="_blank" rel="noopener">${linkText}</a>`;
-      }
-      return _match;
-    },
-  );
+}
+
+// tokens are found in the raw text and escaped afterwards, so the tokenizer sees what
+// the author typed (same as the server's) rather than html entities
+function linkToken(match: RegExpMatchArray, target: string): string {
+  const [raw, linkText, url, lead, handle, tag] = match;
+  if (tag !== undefined) {
+    if (/^\p{N}+$/u.test(tag)) return escapeHtml(raw);
+    return `<a href="/tag/${encodeURIComponent(tag.normalize("NFC").toLowerCase())}" class="hashtag"${target}>#${escapeHtml(tag)}</a>`;
+  }
+  if (handle !== undefined) {
+    return `${escapeHtml(lead)}<a href="/@${handle.toLowerCase()}" class="mention"${target}>@${handle}</a>`;
+  }
+  if (url && (url.startsWith("http://") || url.startsWith("https://"))) {
+    return `<a href="${escapeHtml(url)}" target="_blank" rel="noopener">${escapeHtml(linkText)}</a>`;
+  }
+  return escapeHtml(raw);
+}
+
+// `newTab` sends mentions and tags to a new tab too, for embeds framed in other sites
+// where following a link inside the iframe would strand the reader
+export function parseLinks(text: string, { newTab = false } = {}): string {
+  if (!text) return "";
+  const target = newTab ? ' target="_blank" rel="noopener"' : "";
+  let html = "";
+  let last = 0;
+  for (const match of text.matchAll(LINK_TOKENS)) {
+    html += escapeHtml(text.slice(last, match.index)) + linkToken(match, target);
+    last = match.index! + match[0].length;
+  }
+  return html + escapeHtml(text.slice(last));
 }
 
 export function parseStatusUri(uri: string): { did: string; rkey: string } {
@@ -0,0 +1,52 @@
+import { describe, expect, it } from "vite-plus/test";
+import { MAX_EXPIRY_SECONDS, expiresInSeconds, extendedExpiry } from "./expiry";
+
+const now = Date.parse("2025-03-01T12:00:00.000Z");
+
+describe("extendedExpiry", () => {
+  it("pushes the current expiry out by the duration", () => {
+    expect(extendedExpiry("2025-03-01T12:30:00.000Z", 3600, now)).toEqual({
+      expires: "2025-03-01T13:30:00.000Z",
+    });
+  });
+
+  it("reads offsets as instants", () => {
+    expect(extendedExpiry("2025-03-01T07:30:00-05:00", 1800, now)).toEqual({
+      expires: "2025-03-01T13:00:00.000Z",
+    });
+  });
+
+  it("refuses a status that has already expired", () => {
+    expect(extendedExpiry("2025-03-01T12:00:00.000Z", 3600, now)).toEqual({ error: "status_expired" });
+    expect(extendedExpiry("2025-03-01T11:00:00.000Z", 3600, now)).toEqual({ error: "status_expired" });
+  });
+
+  it("refuses a status that never expires", () => {
+    expect(extendedExpiry(undefined, 3600, now)).toEqual({ error: "no_expiry" });
+    expect(extendedExpiry("later", 3600, now)).toEqual({ error: "no_expiry" });
+  });
+
+  it("rejects durations that aren't positive whole seconds", () => {
+    for (const seconds of [0, -60, 1.5, NaN]) {
+      expect(extendedExpiry("2025-03-01T12:30:00.000Z", seconds, now)).toEqual({ error: "invalid_duration" });
+    }
+  });
+
+  it("caps the new expiry at a week from now", () => {
+    const soon = "2025-03-01T12:30:00.000Z";
+    expect(extendedExpiry(soon, MAX_EXPIRY_SECONDS - 1800, now)).toEqual({ expires: "2025-03-08T12:00:00.000Z" });
+    expect(extendedExpiry(soon, MAX_EXPIRY_SECONDS, now)).toEqual({ error: "duration_too_long" });
+  });
+});
+
+describe("expiresInSeconds", () => {
+  it("counts whole seconds down to zero", () => {
+    expect(expiresInSeconds("2025-03-01T12:01:30.900Z", now)).toBe(90);
+    expect(expiresInSeconds("2025-03-01T11:00:00.000Z", now)).toBe(0);
+  });
+
+  it("is undefined without a usable expiry", () => {
+    expect(expiresInSeconds(undefined, now)).toBeUndefined();
+    expect(expiresInSeconds("tomorrow", now)).toBeUndefined();
+  });
+});
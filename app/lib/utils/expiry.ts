@@ -0,0 +1,44 @@
+// extending a status pushes its expiry further out without reposting it. the
+// longest preset when posting is a week, so that's as far ahead as an extension
+// can reach too.
+export const MAX_EXPIRY_SECONDS = 7 * 24 * 60 * 60;
+
+export const EXTEND_PRESETS = [
+  { seconds: 30 * 60, label: "+30 min" },
+  { seconds: 60 * 60, label: "+1 hour" },
+  { seconds: 4 * 60 * 60, label: "+4 hours" },
+  { seconds: 24 * 60 * 60, label: "+1 day" },
+];
+
+// "status_expired" lets the ui offer a repost instead; there's nothing left to extend
+export type ExtendError =
+  | "status_expired"
+  | "no_expiry"
+  | "invalid_duration"
+  | "duration_too_long";
+
+export function extendedExpiry(
+  expires: string | undefined,
+  seconds: number,
+  now = Date.now(),
+): { expires: string } | { error: ExtendError } {
+  if (!Number.isInteger(seconds) || seconds <= 0) return { error: "invalid_duration" };
+  if (!expires) return { error: "no_expiry" };
+  const current = Date.parse(expires);
+  if (Number.isNaN(current)) return { error: "no_expiry" };
+  if (current <= now) return { error: "status_expired" };
+
+  const next = current + seconds * 1000;
+  if (next - now > MAX_EXPIRY_SECONDS * 1000) return { error: "duration_too_long" };
+  return { expires: new Date(next).toISOString() };
+}
+
+// whole seconds until `expires`, never negative; saves api clients the clock math
+export function expiresInSeconds(
+  expires: string | undefined,
+  now = Date.now(),
+): number | undefined {
+  const at = expires ? Date.parse(expires) : NaN;
+  if (Number.isNaN(at)) return undefined;
+  return Math.max(0, Math.floor((at - now) / 1000));
+}
@@ -0,0 +1,20 @@
+import { describe, expect, it } from "vite-plus/test";
+import { cleanHandle } from "./handle";
+
+describe("cleanHandle", () => {
+  it("lowercases, trims and drops a leading @", () => {
+    expect(cleanHandle("  @Alice.Bsky.Social ")).toBe("alice.bsky.social");
+  });
+
+  it("converts internationalized labels to punycode", () => {
+    expect(cleanHandle("Bücher.Example.com")).toBe("xn--bcher-kva.example.com");
+    expect(cleanHandle("xn--bcher-kva.example.com")).toBe("xn--bcher-kva.example.com");
+  });
+
+  it("never lets the url parser pick a different host", () => {
+    expect(cleanHandle("ä@alice.bsky.social")).toBe("ä@alice.bsky.social");
+    expect(cleanHandle("bücher.de/x")).toBe("bücher.de/x");
+    expect(cleanHandle("bücher.de:8080")).toBe("bücher.de:8080");
+    expect(cleanHandle("b%C3%BCcher.de")).toBe("b%c3%bccher.de");
+  });
+});
@@ -0,0 +1,18 @@
+// characters the URL parser treats as structure (userinfo, port, path, query, fragment)
+// or decodes (%xx). a handle never contains them, and letting them through would make
+// `ä@alice.bsky.social` come out as `alice.bsky.social`.
+const URL_SYNTAX = /[@/:?#\\%\s]/;
+
+// handles are domain names: case-insensitive, and internationalized ones travel as
+// punycode. the URL parser does the IDNA conversion in both the browser and node, so
+// `Bücher.Example.com` and `xn--bcher-kva.example.com` come out the same. anything
+// that isn't a bare hostname is returned as-is for handle validation to reject.
+export function cleanHandle(input: string): string {
+  const handle = input.trim().replace(/^@/, "").toLowerCase();
+  if (/^[\x00-\x7f]*$/.test(handle) || URL_SYNTAX.test(handle)) return handle;
+  try {
+    return new URL(`http://${handle}`).hostname;
+  } catch {
+    return handle;
+  }
+}
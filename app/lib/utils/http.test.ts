@@ -0,0 +1,98 @@
+import { describe, expect, it } from "vite-plus/test";
+import { isNotModified, notModified, parseHttpDate, statusValidators } from "./http";
+
+const status = {
+  uri: "at://did:plc:alice/io.zzstoatzz.status.record/3kabc",
+  indexedAt: "2025-03-01T12:00:02.750Z",
+};
+
+// what a crawler sends back after caching a response carrying `validators`
+const revalidate = (validators: Record<string, string>, use: "etag" | "date") =>
+  new Headers(
+    use === "etag"
+      ? { "if-none-match": validators.etag }
+      : { "if-modified-since": validators["last-modified"] },
+  );
+
+describe("statusValidators", () => {
+  it("derives Last-Modified from indexedAt at second precision", () => {
+    const headers = statusValidators(status);
+    expect(headers["last-modified"]).toBe("Sat, 01 Mar 2025 12:00:02 GMT");
+    expect(parseHttpDate(headers["last-modified"])).toBe(Date.parse("2025-03-01T12:00:02Z"));
+    expect(headers.etag).toMatch(/^W\/"3kabc-[0-9a-z]+"$/);
+  });
+
+  it("moves forward once the status expires", () => {
+    const expiring = { ...status, expires: "2025-03-01T13:00:00.000Z" };
+    const before = statusValidators(expiring, Date.parse("2025-03-01T12:30:00Z"));
+    const after = statusValidators(expiring, Date.parse("2025-03-01T13:00:01Z"));
+    expect(before["last-modified"]).toBe("Sat, 01 Mar 2025 12:00:02 GMT");
+    expect(after["last-modified"]).toBe("Sat, 01 Mar 2025 13:00:00 GMT");
+    expect(after.etag).not.toBe(before.etag);
+  });
+
+  it("sets nothing when indexedAt isn't a date", () => {
+    expect(statusValidators({ ...status, indexedAt: "soon" })).toEqual({});
+  });
+});
+
+describe("isNotModified", () => {
+  it("goes 200 → 304 once the client revalidates an unchanged status", () => {
+    const validators = statusValidators(status);
+    const response = new Headers(validators);
+
+    expect(isNotModified(new Headers(), response)).toBe(false);
+    expect(isNotModified(revalidate(validators, "date"), response)).toBe(true);
+    expect(isNotModified(revalidate(validators, "etag"), response)).toBe(true);
+  });
+
+  it("serves the page again after the status is re-indexed", () => {
+    const cached = statusValidators(status);
+    const updated = new Headers(
+      statusValidators({ ...status, indexedAt: "2025-03-01T12:05:00.000Z" }),
+    );
+
+    expect(isNotModified(revalidate(cached, "date"), updated)).toBe(false);
+    expect(isNotModified(revalidate(cached, "etag"), updated)).toBe(false);
+  });
+
+  it("tolerates sub-second indexedAt against a whole-second If-Modified-Since", () => {
+    const response = new Headers(statusValidators(status));
+    const request = new Headers({ "if-modified-since": "Sat, 01 Mar 2025 12:00:02 GMT" });
+    expect(isNotModified(request, response)).toBe(true);
+  });
+
+  it("lets If-None-Match win over a matching If-Modified-Since", () => {
+    const validators = statusValidators(status);
+    const request = new Headers({
+      "if-none-match": 'W/"3kxyz-0"',
+      "if-modified-since": validators["last-modified"],
+    });
+    expect(isNotModified(request, new Headers(validators))).toBe(false);
+  });
+
+  it("ignores malformed dates and responses without validators", () => {
+    const request = new Headers({ "if-modified-since": "yesterday" });
+    expect(isNotModified(request, new Headers(statusValidators(status)))).toBe(false);
+    expect(isNotModified(revalidate(statusValidators(status), "date"), new Headers())).toBe(false);
+  });
+});
+
+describe("notModified", () => {
+  it("drops the body but keeps caching headers", () => {
+    const response = new Response("<html>", {
+      headers: {
+        "content-type": "text/html",
+        "cache-control": "public, max-age=30",
+        vary: "Accept",
+        ...statusValidators(status),
+      },
+    });
+    const result = notModified(response);
+    expect(result.status).toBe(304);
+    expect(result.body).toBeNull();
+    expect(result.headers.get("content-type")).toBeNull();
+    expect(result.headers.get("vary")).toBe("Accept");
+    expect(result.headers.get("etag")).toBe(response.headers.get("etag"));
+  });
+});
@@ -0,0 +1,83 @@
+// conditional GETs for the pages link-preview crawlers hit over and over. a status
+// page only changes when its status is (re)indexed or crosses its expiry, so that
+// moment is the Last-Modified, and the status's rkey plus that moment is the ETag.
+
+export interface StatusValidated {
+  uri: string;
+  indexedAt: string;
+  expires?: string;
+}
+
+// http dates carry whole seconds only
+export function httpDate(ms: number): string {
+  return new Date(ms).toUTCString();
+}
+
+export function parseHttpDate(value: string | null): number | null {
+  if (!value) return null;
+  const ms = Date.parse(value);
+  return Number.isNaN(ms) ? null : Math.floor(ms / 1000) * 1000;
+}
+
+// when the rendered status last changed: re-indexed, or flipped to expired
+export function changedAt(status: StatusValidated, now = Date.now()): number | null {
+  const indexed = Date.parse(status.indexedAt);
+  if (Number.isNaN(indexed)) return null;
+  const expires = status.expires ? Date.parse(status.expires) : NaN;
+  return expires <= now ? Math.max(indexed, expires) : indexed;
+}
+
+// Last-Modified / ETag for a page showing `status` (for lists, the newest one).
+// the rkey is in the etag so a deleted newest status can't revalidate its successor.
+export function statusValidators(
+  status: StatusValidated,
+  now = Date.now(),
+): Record<string, string> {
+  const changed = changedAt(status, now);
+  if (changed === null) return {};
+  const seconds = Math.floor(changed / 1000);
+  const rkey = status.uri.split("/").pop();
+  return {
+    "last-modified": httpDate(seconds * 1000),
+    etag: `W/"${rkey}-${seconds.toString(36)}"`,
+  };
+}
+
+const opaque = (tag: string) => tag.trim().replace(/^W\//, "");
+
+// whether `response` is unchanged from what the client already holds. If-None-Match
+// wins over If-Modified-Since when both are sent (RFC 9110 §13.2.2).
+export function isNotModified(request: Headers, response: Headers): boolean {
+  const ifNoneMatch = request.get("if-none-match");
+  if (ifNoneMatch !== null) {
+    const etag = response.get("etag");
+    if (!etag) return false;
+    return (
+      ifNoneMatch.trim() === "*" ||
+      ifNoneMatch.split(",").some((tag) => opaque(tag) === opaque(etag))
+    );
+  }
+
+  const since = parseHttpDate(request.get("if-modified-since"));
+  const modified = parseHttpDate(response.get("last-modified"));
+  return since !== null && modified !== null && modified <= since;
+}
+
+const KEPT_ON_304 = [
+  "cache-control",
+  "content-location",
+  "date",
+  "etag",
+  "expires",
+  "last-modified",
+  "vary",
+];
+
+export function notModified(response: Response): Response {
+  const headers = new Headers();
+  for (const name of KEPT_ON_304) {
+    const value = response.headers.get(name);
+    if (value !== null) headers.set(name, value);
+  }
+  return new Response(null, { status: 304, headers });
+}
@@ -0,0 +1,80 @@
+import { describe, expect, it } from "vite-plus/test";
+import { buildCalendar, escapeIcsText, foldIcsLine, statusEnd } from "./ics";
+
+describe("escapeIcsText", () => {
+  it("escapes commas, semicolons and backslashes", () => {
+    expect(escapeIcsText("a,b;c\\d")).toBe("a\\,b\\;c\\\\d");
+  });
+
+  it("turns newlines into literal \\n", () => {
+    expect(escapeIcsText("one\ntwo\r\nthree")).toBe("one\\ntwo\\nthree");
+  });
+});
+
+describe("foldIcsLine", () => {
+  const octets = (s: string) => new TextEncoder().encode(s).length;
+
+  it("leaves short lines alone", () => {
+    expect(foldIcsLine("SUMMARY:hi")).toBe("SUMMARY:hi");
+  });
+
+  it("folds at 75 octets with a leading space on continuations", () => {
+    const folded = foldIcsLine("X".repeat(200));
+    const lines = folded.split("\r\n");
+    expect(lines[0]).toHaveLength(75);
+    expect(lines.slice(1).every((l) => l.startsWith(" ") && octets(l) <= 75)).toBe(true);
+    expect(lines.map((l, i) => (i === 0 ? l : l.slice(1))).join("")).toBe("X".repeat(200));
+  });
+
+  it("never splits a multi-byte emoji across lines", () => {
+    const line = `SUMMARY:${"🎧".repeat(40)}`;
+    const lines = foldIcsLine(line).split("\r\n");
+    expect(lines.every((l) => octets(l) <= 75)).toBe(true);
+    expect(lines.slice(1).every((l) => /^ (?:🎧)+$/u.test(l))).toBe(true);
+    expect(lines.map((l, i) => (i === 0 ? l : l.slice(1))).join("")).toBe(line);
+  });
+});
+
+describe("statusEnd", () => {
+  const start = "2025-01-01T10:00:00.000Z";
+
+  it("uses the expiry when there's no later status", () => {
+    expect(statusEnd(start, "2025-01-01T12:00:00.000Z", undefined)?.toISOString()).toBe(
+      "2025-01-01T12:00:00.000Z",
+    );
+  });
+
+  it("falls back to the next status when there's no expiry", () => {
+    expect(statusEnd(start, undefined, "2025-01-01T11:00:00.000Z")?.toISOString()).toBe(
+      "2025-01-01T11:00:00.000Z",
+    );
+  });
+
+  it("takes whichever comes first", () => {
+    expect(
+      statusEnd(start, "2025-01-01T12:00:00.000Z", "2025-01-01T11:00:00.000Z")?.toISOString(),
+    ).toBe("2025-01-01T11:00:00.000Z");
+  });
+
+  it("is null for the open-ended current status or an end before the start", () => {
+    expect(statusEnd(start, undefined, undefined)).toBeNull();
+    expect(statusEnd(start, "2025-01-01T09:00:00.000Z", undefined)).toBeNull();
+  });
+});
+
+describe("buildCalendar", () => {
+  it("omits DTEND for the current status and ends older ones at their successor", () => {
+    const ics = buildCalendar(
+      [
+        { uri: "at://did:plc:a/io.zzstoatzz.status.record/2", emoji: "🎧", createdAt: "2025-01-01T11:00:00.000Z" },
+        { uri: "at://did:plc:a/io.zzstoatzz.status.record/1", emoji: "☕", text: "coffee, then work", createdAt: "2025-01-01T10:00:00.000Z" },
+      ],
+      "@alice status",
+    );
+    const events = ics.split("BEGIN:VEVENT").slice(1);
+    expect(events[0]).not.toContain("DTEND");
+    expect(events[1]).toContain("DTEND:20250101T110000Z");
+    expect(events[1]).toContain("SUMMARY:☕ coffee\\, then work");
+    expect(ics.endsWith("END:VCALENDAR\r\n")).toBe(true);
+  });
+});
@@ -0,0 +1,95 @@
+import { isCustomEmoji, customEmojiName } from "./emoji";
+
+export interface CalendarStatus {
+  uri: string;
+  emoji: string;
+  text?: string;
+  createdAt: string;
+  expires?: string;
+}
+
+// RFC 5545 §3.3.11: backslash, semicolon, comma and newlines must be escaped in TEXT values
+export function escapeIcsText(text: string): string {
+  return text
+    .replace(/\\/g, "\\\\")
+    .replace(/;/g, "\\;")
+    .replace(/,/g, "\\,")
+    .replace(/\r?\n/g, "\\n");
+}
+
+// RFC 5545 §3.1: content lines longer than 75 octets are folded with CRLF + a single space.
+// iterate by code point so a multi-byte emoji never gets split across lines.
+export function foldIcsLine(line: string): string {
+  const encoder = new TextEncoder();
+  const lines: string[] = [];
+  let current = "";
+  let octets = 0;
+  for (const char of line) {
+    const size = encoder.encode(char).length;
+    // continuation lines spend one octet on the leading space
+    const limit = lines.length === 0 ? 75 : 74;
+    if (octets + size > limit) {
+      lines.push(current);
+      current = "";
+      octets = 0;
+    }
+    current += char;
+    octets += size;
+  }
+  lines.push(current);
+  return lines.join("\r\n ");
+}
+
+export function formatIcsDate(date: Date): string {
+  return date.toISOString().replace(/[-:]/g, "").replace(/\.\d{3}/, "");
+}
+
+// a status ends when it expires or when the next one replaces it, whichever comes first.
+// returns null when neither exists (still the current status) or the end isn't after the start.
+export function statusEnd(
+  createdAt: string,
+  expires: string | undefined,
+  nextCreatedAt: string | undefined,
+): Date | null {
+  const start = new Date(createdAt).getTime();
+  const ends = [expires, nextCreatedAt]
+    .filter((d): d is string => !!d)
+    .map((d) => new Date(d).getTime())
+    .filter((t) => !Number.isNaN(t) && t > start);
+  return ends.length > 0 ? new Date(Math.min(...ends)) : null;
+}
+
+function summary(status: CalendarStatus): string {
+  const label = isCustomEmoji(status.emoji)
+    ? `:${customEmojiName(status.emoji)}:`
+    : status.emoji;
+  return status.text ? `${label} ${status.text}` : label;
+}
+
+// statuses are expected newest first, the order the actor feed returns them in
+export function buildCalendar(statuses: CalendarStatus[], name: string): string {
+  const stamp = formatIcsDate(new Date());
+  const lines = [
+    "BEGIN:VCALENDAR",
+    "VERSION:2.0",
+    "PRODID:-//status//status history//EN",
+    "CALSCALE:GREGORIAN",
+    `X-WR-CALNAME:${escapeIcsText(name)}`,
+  ];
+
+  statuses.forEach((status, i) => {
+    const end = statusEnd(status.createdAt, status.expires, statuses[i - 1]?.createdAt);
+    lines.push(
+      "BEGIN:VEVENT",
+      `UID:${escapeIcsText(status.uri)}`,
+      `DTSTAMP:${stamp}`,
+      `DTSTART:${formatIcsDate(new Date(status.createdAt))}`,
+      ...(end ? [`DTEND:${formatIcsDate(end)}`] : []),
+      `SUMMARY:${escapeIcsText(summary(status))}`,
+      "END:VEVENT",
+    );
+  });
+
+  lines.push("END:VCALENDAR");
+  return lines.map(foldIcsLine).join("\r\n") + "\r\n";
+}
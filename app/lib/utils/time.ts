@@ -107,3 +107,28 @@ export function relativeTimeFuture(dateStr: string): string {
     timeStr
   );
 }
+
+// archive days are UTC calendar days written as YYYY-MM-DD
+export function parseArchiveDate(date: string): Date | null {
+  if (!/^\d{4}-\d{2}-\d{2}$/.test(date)) return null;
+  const day = new Date(`${date}T00:00:00.000Z`);
+  if (Number.isNaN(day.getTime()) || day.toISOString().slice(0, 10) !== date) return null;
+  return day;
+}
+
+export function shiftArchiveDate(date: string, days: number): string {
+  const day = new Date(`${date}T00:00:00.000Z`);
+  day.setUTCDate(day.getUTCDate() + days);
+  return day.toISOString().slice(0, 10);
+}
+
+export function formatDuration(ms: number): string {
+  const mins = Math.floor(ms / 60000);
+  const hours = Math.floor(mins / 60);
+  const days = Math.floor(hours / 24);
+
+  if (mins < 1) return "less than a minute";
+  if (mins < 60) return `${mins}m`;
+  if (hours < 24) return mins % 60 === 0 ? `${hours}h` : `${hours}h ${mins % 60}m`;
+  return hours % 24 === 0 ? `${days}d` : `${days}d ${hours % 24}h`;
+}
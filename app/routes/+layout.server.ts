@@ -1,11 +1,15 @@
+import { env } from "$env/dynamic/private";
 import { callXrpc, parseViewer } from "$hatk/client";
 import type { LayoutServerLoad } from "./$types";
 
-export const load: LayoutServerLoad = async ({ cookies }) => {
+export const load: LayoutServerLoad = async ({ cookies, url }) => {
   const viewer = await parseViewer(cookies);
 
   return {
     viewer,
+    // the public origin for links people copy out (embed code, share links), the
+    // same one /oembed describes
+    origin: env.EXTERNAL_BASE_URL ?? url.origin,
     preferences: viewer
       ? callXrpc("dev.hatk.getPreferences").catch(() => null)
       : null,
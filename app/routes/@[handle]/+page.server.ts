@@ -1,22 +1,22 @@
-import { redirect } from "@sveltejs/kit";
-import { callXrpc } from "$hatk/client";
+import { error, redirect } from "@sveltejs/kit";
+import { DID, displayHandle, normalizeHandle, resolveHandle } from "$lib/server/identity";
 import type { PageServerLoad } from "./$types";
 
 export const load: PageServerLoad = async ({ params }) => {
-  const handle = decodeURIComponent(params.handle);
+  const input = decodeURIComponent(params.handle);
+  if (DID.test(input)) redirect(302, `/profile/${encodeURIComponent(input)}`);
 
+  const handle = normalizeHandle(input);
+  if (!handle) error(404, "not a valid handle");
+
+  let did: string | null;
   try {
-    const res = await callXrpc("dev.hatk.getFeed", {
-      feed: "actor",
-      actor: handle,
-      limit: 1,
-    });
-    const did = res.items?.[0]?.did;
-    if (did) {
-      redirect(302, `/profile/${encodeURIComponent(did)}`);
-    }
-  } catch {}
+    did = await resolveHandle(handle);
+  } catch {
+    // resolver unavailable — redirect anyway, profile page will show "no statuses yet"
+    redirect(302, `/profile/${encodeURIComponent(handle)}`);
+  }
+  if (!did) error(404, `@${displayHandle(handle)} doesn't exist`);
 
-  // no statuses found — redirect anyway, profile page will show "no statuses yet"
-  redirect(302, `/profile/${encodeURIComponent(handle)}`);
+  redirect(302, `/profile/${encodeURIComponent(did)}`);
 };
@@ -0,0 +1,55 @@
+import { error } from "@sveltejs/kit";
+import { callXrpc } from "$hatk/client";
+import { buildCalendar, type CalendarStatus } from "$lib/utils/ics";
+import {
+  DID,
+  displayHandle,
+  normalizeActor,
+  resolveActor,
+  type Account,
+} from "$lib/server/identity";
+import type { RequestHandler } from "./$types";
+
+const MAX_EVENTS = 500;
+
+export const GET: RequestHandler = async ({ params }) => {
+  const actor = normalizeActor(decodeURIComponent(params.handle));
+  if (!actor) error(404, "not a valid handle or DID");
+
+  // unknown accounts 404 like /@{handle} does, rather than serving an empty calendar
+  let account: Account | null;
+  try {
+    account = await resolveActor(actor);
+  } catch {
+    error(502, "couldn't resolve that account");
+  }
+  if (!account) error(404, `${DID.test(actor) ? actor : `@${displayHandle(actor)}`} doesn't exist`);
+  const { did, handle } = account;
+
+  const statuses: CalendarStatus[] = [];
+  let cursor: string | undefined;
+  try {
+    do {
+      const res = await callXrpc("dev.hatk.getFeed", {
+        feed: "actor",
+        actor: did,
+        limit: 100,
+        ...(cursor ? { cursor } : {}),
+      });
+      statuses.push(...(res.items ?? []));
+      cursor = res.cursor;
+    } while (cursor && statuses.length < MAX_EVENTS);
+  } catch {
+    error(502, "failed to load statuses");
+  }
+
+  const name = handle ?? statuses[0]?.handle ?? did;
+  const label = name === did ? did : `@${displayHandle(name)}`;
+
+  return new Response(buildCalendar(statuses.slice(0, MAX_EVENTS), `${label} status`), {
+    headers: {
+      "content-type": "text/calendar; charset=utf-8",
+      "content-disposition": `inline; filename="${name.replace(/[^\w.-]/g, "_")}.ics"`,
+    },
+  });
+};
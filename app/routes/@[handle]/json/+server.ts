@@ -0,0 +1,5 @@
+import { handleStatusResponse } from "$lib/server/status";
+import type { RequestHandler } from "./$types";
+
+export const GET: RequestHandler = async ({ params }) =>
+  handleStatusResponse(decodeURIComponent(params.handle));
@@ -0,0 +1,23 @@
+import { error } from "@sveltejs/kit";
+import { WeekOutOfReach, isoWeekRange, loadWrapped } from "$lib/server/wrapped";
+import { displayHandle, normalizeActor } from "$lib/server/identity";
+import type { PageServerLoad } from "./$types";
+
+export const load: PageServerLoad = async ({ params }) => {
+  const handle = normalizeActor(decodeURIComponent(params.handle));
+  if (!handle) error(404, "not a valid handle or DID");
+  const range = isoWeekRange(params.week);
+  if (!range) error(400, "week must look like 2025-W07");
+  if (range.start.getTime() > Date.now()) error(404, "that week hasn't happened yet");
+
+  let summary;
+  try {
+    summary = await loadWrapped(handle, range);
+  } catch (e) {
+    if (e instanceof WeekOutOfReach) error(422, "that week is too far back to summarize");
+    error(502, "failed to load statuses");
+  }
+  if (!summary) error(404, `no statuses from @${displayHandle(handle)} that week`);
+
+  return { summary, displayName: displayHandle(summary.handle) };
+};
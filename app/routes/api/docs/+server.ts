@@ -0,0 +1,12 @@
+import { json } from "@sveltejs/kit";
+import { ROUTE_DOCS } from "$lib/server/docs";
+import type { RequestHandler } from "./$types";
+
+export const GET: RequestHandler = async () =>
+  json(
+    {
+      routes: ROUTE_DOCS,
+      xrpc: "xrpc methods (dev.hatk.getFeed and friends) are served under /xrpc and defined by their lexicons",
+    },
+    { headers: { "cache-control": "public, max-age=3600" } },
+  );
@@ -0,0 +1,32 @@
+import { error, json } from "@sveltejs/kit";
+import { normalizeHandle, resolveHandle } from "$lib/server/identity";
+import { mapConcurrent } from "$lib/server/concurrency";
+import { rateLimited } from "$lib/server/ratelimit";
+import type { RequestHandler } from "./$types";
+
+const MAX_HANDLES = 100;
+const CONCURRENCY = 8;
+
+// handle → did, keyed by the handle as sent; unknown or unresolvable handles map to null
+export const POST: RequestHandler = async ({ request, getClientAddress }) => {
+  if (rateLimited(`resolve-dids:${getClientAddress()}`, 20, 60_000)) {
+    error(429, "too many lookups, slow down");
+  }
+
+  const body = await request.json().catch(() => null);
+  const input: unknown = body?.handles;
+  if (!Array.isArray(input) || input.length === 0) {
+    error(400, "expected { handles: [...] } with at least one handle");
+  }
+  if (input.length > MAX_HANDLES) error(400, `at most ${MAX_HANDLES} handles per request`);
+  const invalid = input.filter((h) => typeof h !== "string" || !normalizeHandle(h));
+  if (invalid.length > 0) {
+    error(400, `not a handle: ${invalid.map((h) => JSON.stringify(h)).join(", ")}`);
+  }
+
+  const handles = [...new Set(input as string[])];
+  const resolved = await mapConcurrent(handles, CONCURRENCY, (handle) =>
+    resolveHandle(normalizeHandle(handle)!).catch(() => null),
+  );
+  return json({ dids: Object.fromEntries(handles.map((handle, i) => [handle, resolved[i]])) });
+};
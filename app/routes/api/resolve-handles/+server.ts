@@ -0,0 +1,32 @@
+import { error, json } from "@sveltejs/kit";
+import { DID, resolveDid } from "$lib/server/identity";
+import { mapConcurrent } from "$lib/server/concurrency";
+import { rateLimited } from "$lib/server/ratelimit";
+import type { RequestHandler } from "./$types";
+
+const MAX_DIDS = 100;
+const CONCURRENCY = 8;
+
+// did → handle for DIDs the client runs into; unknown or unresolvable DIDs map to null
+export const POST: RequestHandler = async ({ request, getClientAddress }) => {
+  if (rateLimited(`resolve-handles:${getClientAddress()}`, 20, 60_000)) {
+    error(429, "too many lookups, slow down");
+  }
+
+  const body = await request.json().catch(() => null);
+  const input: unknown = body?.dids;
+  if (!Array.isArray(input) || input.length === 0) {
+    error(400, "expected { dids: [...] } with at least one DID");
+  }
+  if (input.length > MAX_DIDS) error(400, `at most ${MAX_DIDS} DIDs per request`);
+  const invalid = input.filter((d) => typeof d !== "string" || !DID.test(d));
+  if (invalid.length > 0) {
+    error(400, `not a DID: ${invalid.map((d) => JSON.stringify(d)).join(", ")}`);
+  }
+
+  const dids = [...new Set(input as string[])];
+  const resolved = await mapConcurrent(dids, CONCURRENCY, (did) =>
+    resolveDid(did).then((info) => info?.handle ?? null).catch(() => null),
+  );
+  return json({ handles: Object.fromEntries(dids.map((did, i) => [did, resolved[i]])) });
+};
@@ -0,0 +1,41 @@
+import { error, json } from "@sveltejs/kit";
+import { normalizeActor } from "$lib/server/identity";
+import { rateLimited } from "$lib/server/ratelimit";
+import { loadCurrentStatuses, type CurrentStatus } from "$lib/server/status";
+import type { RequestHandler } from "./$types";
+
+const MAX_ACTORS = 50;
+
+export const POST: RequestHandler = async ({ request, getClientAddress }) => {
+  if (rateLimited(`statuses-batch:${getClientAddress()}`, 60, 60_000)) {
+    error(429, "too many requests, slow down");
+  }
+
+  const body = await request.json().catch(() => null);
+  const actors: unknown = body?.actors;
+  if (!Array.isArray(actors) || actors.length === 0) {
+    error(400, "expected { actors: [...] } with at least one handle or DID");
+  }
+  if (actors.length > MAX_ACTORS) {
+    error(400, `at most ${MAX_ACTORS} actors per request`);
+  }
+  const invalid = actors.filter((a) => typeof a !== "string" || !normalizeActor(a));
+  if (invalid.length > 0) {
+    error(400, `not a handle or DID: ${invalid.map((a) => JSON.stringify(a)).join(", ")}`);
+  }
+
+  // keyed by the identifier exactly as requested, so callers can look their input back up
+  const requested = [...new Set(actors as string[])];
+  let current: Record<string, CurrentStatus>;
+  try {
+    current = await loadCurrentStatuses(requested.map((actor) => normalizeActor(actor)!));
+  } catch {
+    error(502, "couldn't reach the status index");
+  }
+
+  return json({
+    statuses: Object.fromEntries(
+      requested.map((actor) => [actor, current[normalizeActor(actor)!]]),
+    ),
+  });
+};
@@ -0,0 +1,24 @@
+import { json } from "@sveltejs/kit";
+import { MAX_TRENDING, loadTrendingTags, type TrendingTags } from "$lib/server/trending";
+import type { RequestHandler } from "./$types";
+
+const DEFAULT_LIMIT = 20;
+
+export const GET: RequestHandler = async ({ url }) => {
+  const requested = Number(url.searchParams.get("limit") ?? DEFAULT_LIMIT);
+  if (!Number.isInteger(requested) || requested < 1 || requested > MAX_TRENDING) {
+    return json({ error: "invalid_limit", max: MAX_TRENDING }, { status: 400 });
+  }
+
+  let trending: TrendingTags;
+  try {
+    trending = await loadTrendingTags();
+  } catch {
+    return json({ error: "upstream_failed" }, { status: 502 });
+  }
+
+  return json(
+    { ...trending, tags: trending.tags.slice(0, requested) },
+    { headers: { "cache-control": "public, max-age=300" } },
+  );
+};
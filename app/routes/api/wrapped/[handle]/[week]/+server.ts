@@ -0,0 +1,27 @@
+import { json } from "@sveltejs/kit";
+import { WeekOutOfReach, isoWeekRange, loadWrapped } from "$lib/server/wrapped";
+import { normalizeActor } from "$lib/server/identity";
+import type { RequestHandler } from "./$types";
+
+export const GET: RequestHandler = async ({ params }) => {
+  const handle = normalizeActor(decodeURIComponent(params.handle));
+  if (!handle) return json({ error: "invalid_actor", handle: params.handle }, { status: 404 });
+  const range = isoWeekRange(params.week);
+  if (!range) return json({ error: "invalid_week", week: params.week }, { status: 400 });
+
+  let summary;
+  try {
+    summary = range.start.getTime() > Date.now() ? null : await loadWrapped(handle, range);
+  } catch (e) {
+    if (e instanceof WeekOutOfReach) {
+      return json({ error: "week_out_of_reach", week: params.week }, { status: 422 });
+    }
+    return json({ error: "upstream_failed" }, { status: 502 });
+  }
+  if (!summary) return json({ error: "no_statuses", handle, week: params.week }, { status: 404 });
+
+  const past = range.end.getTime() <= Date.now();
+  return json(summary, {
+    headers: { "cache-control": past ? "public, max-age=86400" : "public, max-age=60" },
+  });
+};
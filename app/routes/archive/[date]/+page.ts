@@ -0,0 +1,28 @@
+import { browser } from "$app/environment";
+import { error } from "@sveltejs/kit";
+import { archiveFeedQuery, oldestStatusQuery } from "$lib/queries";
+import { parseArchiveDate } from "$lib/utils/time";
+import type { PageLoad } from "./$types";
+
+export const load: PageLoad = async ({ params, url, parent, fetch }) => {
+  const date = params.date;
+  const { queryClient } = await parent();
+
+  // the archive runs from the day of the instance's first status through today (UTC)
+  const today = new Date().toISOString().slice(0, 10);
+  // if the lookup fails, only the upper bound is enforced
+  const oldest = await queryClient.fetchQuery(oldestStatusQuery(fetch)).catch(() => null);
+  const firstCreated = oldest?.items?.[0]?.createdAt;
+  const first = oldest ? (firstCreated ? new Date(firstCreated).toISOString().slice(0, 10) : today) : null;
+
+  const day = parseArchiveDate(date);
+  if (!day) error(400, `expected a UTC date like ${today}`);
+  if ((first && date < first) || date > today) {
+    error(404, `no archive for ${date}: it covers ${first ?? "the beginning"} through ${today}`);
+  }
+
+  const mode = url.searchParams.get("mode") === "active" ? "active" : "created";
+  const prefetch = queryClient.prefetchQuery(archiveFeedQuery(date, mode, 50, fetch));
+  if (!browser) await prefetch;
+  return { date, mode, first };
+};
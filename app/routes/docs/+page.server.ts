@@ -0,0 +1,4 @@
+import { ROUTE_DOCS } from "$lib/server/docs";
+import type { PageServerLoad } from "./$types";
+
+export const load: PageServerLoad = async () => ({ routes: ROUTE_DOCS });
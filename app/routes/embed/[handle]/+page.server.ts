@@ -0,0 +1,37 @@
+import { error } from "@sveltejs/kit";
+import { callXrpc } from "$hatk/client";
+import { isCustomEmoji, customEmojiName, resolveBufoUrl } from "$lib/utils/emoji";
+import { displayHandle, normalizeActor } from "$lib/server/identity";
+import { EMBED_CSP } from "$lib/server/embed";
+import type { PageServerLoad } from "./$types";
+
+// the embed is a static snapshot inside someone else's page: no client js, so the
+// bufo onerror fallback can't run — resolve the image url server-side instead
+export const csr = false;
+
+export const load: PageServerLoad = async ({ params, url, fetch, setHeaders }) => {
+  const handle = normalizeActor(decodeURIComponent(params.handle));
+  if (!handle) error(404, "not a valid handle or DID");
+  const theme = url.searchParams.get("theme") === "light" ? "light" : "dark";
+
+  setHeaders({
+    "cache-control": "public, max-age=60",
+    "content-security-policy": EMBED_CSP,
+  });
+
+  try {
+    const res = await callXrpc("dev.hatk.getFeed", {
+      feed: "actor",
+      actor: handle,
+      limit: 1,
+    });
+    const status = res.items?.[0] ?? null;
+    const emojiUrl =
+      status && isCustomEmoji(status.emoji)
+        ? await resolveBufoUrl(customEmojiName(status.emoji), fetch)
+        : null;
+    return { handle: displayHandle(status?.handle ?? handle), theme, status, emojiUrl };
+  } catch {}
+
+  return { handle: displayHandle(handle), theme, status: null, emojiUrl: null };
+};
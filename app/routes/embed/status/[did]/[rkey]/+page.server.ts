@@ -0,0 +1,44 @@
+import { error } from "@sveltejs/kit";
+import { loadShareView, type ShareView } from "$lib/server/status";
+import { DID, displayHandle } from "$lib/server/identity";
+import { EMBED_CSP } from "$lib/server/embed";
+import type { PageServerLoad } from "./$types";
+
+// the same static snapshot as /embed/{handle}, but pinned to one shared status
+// rather than whatever the author has set right now
+export const csr = false;
+
+export const load: PageServerLoad = async ({ params, url, fetch, setHeaders }) => {
+  const did = decodeURIComponent(params.did);
+  const rkey = decodeURIComponent(params.rkey);
+  if (!DID.test(did)) error(404, "not a valid DID");
+  const theme = url.searchParams.get("theme") === "light" ? "light" : "dark";
+
+  setHeaders({
+    "cache-control": "public, max-age=300",
+    "content-security-policy": EMBED_CSP,
+  });
+
+  let view: ShareView;
+  try {
+    view = await loadShareView(did, rkey, fetch);
+  } catch {
+    error(502, "couldn't reach the status index");
+  }
+  const { status, emojiUrl } = view;
+
+  return {
+    did,
+    rkey,
+    handle: displayHandle(status?.handle ?? did),
+    theme,
+    status: status && {
+      emoji: status.emoji,
+      text: status.text,
+      context: status.context,
+      createdAt: status.createdAt,
+      expires: status.expires,
+    },
+    emojiUrl,
+  };
+};
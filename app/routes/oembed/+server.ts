@@ -0,0 +1,61 @@
+import { error, json } from "@sveltejs/kit";
+import { env } from "$env/dynamic/private";
+import { embedTarget } from "$lib/server/embed";
+import { displayHandle, normalizeActor, resolveActor, type Account } from "$lib/server/identity";
+import type { RequestHandler } from "./$types";
+
+const DEFAULT_WIDTH = 400;
+const DEFAULT_HEIGHT = 120;
+
+function clampDimension(value: string | null, fallback: number): number {
+  const n = Number(value);
+  return Number.isInteger(n) && n > 0 ? Math.min(n, fallback) : fallback;
+}
+
+export const GET: RequestHandler = async ({ url }) => {
+  const origin = env.EXTERNAL_BASE_URL ?? url.origin;
+
+  const format = url.searchParams.get("format") ?? "json";
+  if (format !== "json") error(501, "only format=json is supported");
+
+  const raw = url.searchParams.get("url");
+  if (!raw) error(400, "missing url parameter");
+
+  let target: URL;
+  try {
+    target = new URL(raw);
+  } catch {
+    error(400, "invalid url parameter");
+  }
+
+  // only describe our own pages — never fetch or frame arbitrary urls
+  const embed = embedTarget(target, origin);
+  if (!embed) error(404, "url is not a profile or status page on this instance");
+
+  // don't hand out an iframe for an account that doesn't exist
+  const actor = normalizeActor(embed.actor);
+  if (!actor) error(404, "not a valid handle or DID");
+  let account: Account | null;
+  try {
+    account = await resolveActor(actor);
+  } catch {
+    error(502, "couldn't resolve that account");
+  }
+  if (!account) error(404, "no such account");
+
+  const width = clampDimension(url.searchParams.get("maxwidth"), DEFAULT_WIDTH);
+  const height = clampDimension(url.searchParams.get("maxheight"), DEFAULT_HEIGHT);
+  const src = `${origin}/embed/${embed.path}`;
+  const author = account.handle ? `@${displayHandle(account.handle)}` : account.did;
+
+  return json({
+    version: "1.0",
+    type: "rich",
+    provider_name: "status",
+    provider_url: origin,
+    title: `${author}'s status`,
+    html: `<iframe src="${src}" width="${width}" height="${height}" style="border:none;overflow:hidden" title="status"></iframe>`,
+    width,
+    height,
+  });
+};
@@ -1,11 +1,18 @@
 import { browser } from "$app/environment";
 import { actorFeedQuery } from "$lib/queries";
+import { statusValidators } from "$lib/utils/http";
 import type { PageLoad } from "./$types";
 
-export const load: PageLoad = async ({ params, parent, fetch }) => {
+export const load: PageLoad = async ({ params, parent, fetch, setHeaders }) => {
   const did = decodeURIComponent(params.did);
   const { queryClient } = await parent();
-  const prefetch = queryClient.prefetchQuery(actorFeedQuery(did, 50, fetch));
-  if (!browser) await prefetch;
+  const query = actorFeedQuery(did, 50, fetch);
+  const prefetch = queryClient.prefetchQuery(query);
+  if (!browser) {
+    await prefetch;
+    // the page only changes when their newest status does
+    const newest = queryClient.getQueryData(query.queryKey)?.items?.[0];
+    if (newest) setHeaders(statusValidators(newest));
+  }
   return { did };
 };
@@ -1,40 +1,20 @@
-import { callXrpc } from "$hatk/client";
-import { isCustomEmoji, customEmojiName, resolveBufoUrl } from "$lib/utils/emoji";
+import { error } from "@sveltejs/kit";
+import { DID } from "$lib/server/identity";
+import { loadShareView, type ShareView } from "$lib/server/status";
+import { statusValidators } from "$lib/utils/http";
 import type { PageServerLoad } from "./$types";
 
-// getRecord may return the status fields flat or nested under `value`, so accept both
-type StatusRecord = {
-  emoji?: string;
-  text?: string;
-  handle?: string;
-  createdAt?: string;
-  expires?: string;
-  value?: {
-    emoji?: string;
-    text?: string;
-    handle?: string;
-    createdAt?: string;
-    expires?: string;
-  };
-};
-
-export const load: PageServerLoad = async ({ params, fetch }) => {
+export const load: PageServerLoad = async ({ params, fetch, setHeaders }) => {
   const did = decodeURIComponent(params.did);
   const rkey = decodeURIComponent(params.rkey);
-  const uri = `at://${did}/io.zzstoatzz.status.record/${rkey}`;
+  if (!DID.test(did)) error(404, "not a valid DID");
 
+  let view: ShareView;
   try {
-    const res = await callXrpc("dev.hatk.getRecord", { uri });
-    if (res.record) {
-      const status = res.record as StatusRecord;
-      const emoji = status.value?.emoji ?? status.emoji;
-      const ogImage =
-        emoji && isCustomEmoji(emoji)
-          ? await resolveBufoUrl(customEmojiName(emoji), fetch)
-          : null;
-      return { did, rkey, status, ogImage };
-    }
-  } catch {}
-
-  return { did, rkey, status: null as StatusRecord | null, ogImage: null };
+    view = await loadShareView(did, rkey, fetch);
+  } catch {
+    error(502, "couldn't reach the status index");
+  }
+  if (view.status) setHeaders(statusValidators(view.status));
+  return view;
 };
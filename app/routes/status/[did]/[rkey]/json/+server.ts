@@ -0,0 +1,51 @@
+import { json } from "@sveltejs/kit";
+import { env } from "$env/dynamic/private";
+import { DID } from "$lib/server/identity";
+import { loadShareView, type ShareView } from "$lib/server/status";
+import { resolveClient } from "$lib/atclients";
+import { statusValidators } from "$lib/utils/http";
+import type { RequestHandler } from "./$types";
+
+export const GET: RequestHandler = async ({ params, url, fetch }) => {
+  const did = decodeURIComponent(params.did);
+  const rkey = decodeURIComponent(params.rkey);
+  if (!DID.test(did)) {
+    return json({ error: "invalid_did", did }, { status: 400 });
+  }
+
+  let view: ShareView;
+  try {
+    view = await loadShareView(did, rkey, fetch);
+  } catch {
+    return json({ error: "upstream_failed" }, { status: 502 });
+  }
+
+  const { uri, status, emojiUrl } = view;
+  if (!status) {
+    return json({ error: "status_not_found", uri }, { status: 404 });
+  }
+
+  const origin = env.EXTERNAL_BASE_URL ?? url.origin;
+
+  return json(
+    {
+      uri,
+      did,
+      handle: status.handle,
+      emoji: status.emoji,
+      emojiUrl,
+      text: status.text,
+      tags: status.tags,
+      mentions: status.mentions,
+      context: status.context,
+      via: status.via,
+      createdAt: status.createdAt,
+      expires: status.expires,
+      expired: status.expired,
+      indexedAt: status.indexedAt,
+      shareUrl: `${origin}/status/${did}/${rkey}`,
+      profileUrl: resolveClient("bsky").profileUrl(status.handle),
+    },
+    { headers: statusValidators(status) },
+  );
+};
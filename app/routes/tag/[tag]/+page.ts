@@ -0,0 +1,16 @@
+import { browser } from "$app/environment";
+import { error } from "@sveltejs/kit";
+import { tagFeedQuery } from "$lib/queries";
+import type { PageLoad } from "./$types";
+
+export const load: PageLoad = async ({ params, parent, fetch }) => {
+  const tag = decodeURIComponent(params.tag).replace(/^#/, "").normalize("NFC").toLowerCase();
+  if (!/^[\p{L}\p{M}\p{N}_]{1,64}$/u.test(tag) || /^\p{N}+$/u.test(tag)) {
+    error(404, "not a valid tag");
+  }
+
+  const { queryClient } = await parent();
+  const prefetch = queryClient.prefetchQuery(tagFeedQuery(tag, 50, fetch));
+  if (!browser) await prefetch;
+  return { tag };
+};
@@ -2,6 +2,13 @@ import { defineConfig } from "@hatk/hatk/config";
 
 const isProd = process.env.NODE_ENV === "production";
 
+// RELAY_URL lets a staging deploy point at a different relay/firehose
+const relay =
+  process.env.RELAY_URL ?? (isProd ? "wss://bsky.network" : "ws://localhost:2583");
+if (!/^wss?:\/\//.test(relay)) {
+  throw new Error(`RELAY_URL must be a ws:// or wss:// url, got ${relay}`);
+}
+
 const scopes = [
   "atproto",
   "repo:io.zzstoatzz.status.record",
@@ -9,7 +16,7 @@ const scopes = [
 ].join(" ");
 
 export default defineConfig({
-  relay: isProd ? "wss://bsky.network" : "ws://localhost:2583",
+  relay,
   plc: isProd ? "https://plc.directory" : "http://localhost:2582",
   port: 3000,
   databaseEngine: "sqlite",
@@ -0,0 +1,67 @@
+import { describe, expect, it } from "vite-plus/test";
+import { ACTIVE_LOOKBACK_DAYS, archiveWindow } from "./_archive.ts";
+
+const range = { first: "2024-01-15", today: "2025-03-10" };
+
+describe("archiveWindow", () => {
+  it("spans one UTC day, end exclusive", () => {
+    expect(archiveWindow("2025-03-01", "created", range)).toEqual({
+      start: "2025-03-01T00:00:00.000Z",
+      end: "2025-03-02T00:00:00.000Z",
+      scanFrom: "2025-03-01T00:00:00.000Z",
+    });
+  });
+
+  it("rolls the end over month, year and leap-day boundaries", () => {
+    expect(archiveWindow("2024-02-29", "created", range)).toMatchObject({
+      end: "2024-03-01T00:00:00.000Z",
+    });
+    expect(archiveWindow("2024-12-31", "created", range)).toMatchObject({
+      end: "2025-01-01T00:00:00.000Z",
+    });
+  });
+
+  it("includes the first and last days of the range", () => {
+    expect(archiveWindow("2024-01-15", "created", range)).not.toHaveProperty("error");
+    expect(archiveWindow("2025-03-10", "created", range)).not.toHaveProperty("error");
+  });
+
+  it("rejects dates that don't exist instead of rolling them over", () => {
+    for (const date of ["2025-02-30", "2025-02-29", "2024-13-01", "2024-04-31", "2024-06-00"]) {
+      expect(archiveWindow(date, "created", range)).toEqual({
+        error: "expected a UTC date (YYYY-MM-DD) from 2024-01-15 through 2025-03-10",
+      });
+    }
+  });
+
+  it("rejects malformed dates", () => {
+    for (const date of ["", "2025-3-1", "2025-03-01T00:00:00Z", "20250301", "yesterday"]) {
+      expect(archiveWindow(date, "created", range)).toHaveProperty("error");
+    }
+  });
+
+  it("rejects days outside the archive, naming its range", () => {
+    expect(archiveWindow("2024-01-14", "created", range)).toEqual({
+      error: "expected a UTC date (YYYY-MM-DD) from 2024-01-15 through 2025-03-10",
+    });
+    expect(archiveWindow("2025-03-11", "active", range)).toHaveProperty("error");
+  });
+
+  it("only covers today before the first status exists", () => {
+    const empty = { first: null, today: "2025-03-10" };
+    expect(archiveWindow("2025-03-10", "created", empty)).not.toHaveProperty("error");
+    expect(archiveWindow("2025-03-09", "created", empty)).toEqual({
+      error: "expected a UTC date (YYYY-MM-DD) from 2025-03-10 through 2025-03-10",
+    });
+  });
+
+  it("bounds the active scan a fixed lookback before the day", () => {
+    const day = archiveWindow("2025-03-01", "active", range);
+    expect(ACTIVE_LOOKBACK_DAYS).toBe(30);
+    expect(day).toEqual({
+      start: "2025-03-01T00:00:00.000Z",
+      end: "2025-03-02T00:00:00.000Z",
+      scanFrom: "2025-01-30T00:00:00.000Z",
+    });
+  });
+});
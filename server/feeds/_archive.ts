@@ -0,0 +1,47 @@
+// archive days are UTC calendar days written as YYYY-MM-DD. like the archive page's
+// parseArchiveDate, a date has to survive the round trip through Date: 2025-02-30
+// is rejected rather than rolled over into march.
+const DAY = /^\d{4}-\d{2}-\d{2}$/;
+const DAY_MS = 86_400_000;
+
+// mode=active looks back before the day for statuses still up when it starts. a status
+// with no expiry stays up until its author posts again, so nothing bounds that search;
+// statuses created longer ago than this are left out instead of scanning all history.
+export const ACTIVE_LOOKBACK_DAYS = 30;
+
+export type ArchiveMode = "created" | "active";
+
+export interface ArchiveWindow {
+  // the day itself, [start, end)
+  start: string;
+  end: string;
+  // the earliest created_at the scan has to consider
+  scanFrom: string;
+}
+
+// `first` is the day of the instance's first status (null before there is one) and
+// `today` the current UTC day; the archive covers first through today
+export function archiveWindow(
+  date: string,
+  mode: ArchiveMode,
+  range: { first: string | null; today: string },
+): ArchiveWindow | { error: string } {
+  const first = range.first ?? range.today;
+  const start = DAY.test(date) ? new Date(`${date}T00:00:00.000Z`) : null;
+  if (
+    !start ||
+    Number.isNaN(start.getTime()) ||
+    start.toISOString().slice(0, 10) !== date ||
+    date < first ||
+    date > range.today
+  ) {
+    return { error: `expected a UTC date (YYYY-MM-DD) from ${first} through ${range.today}` };
+  }
+
+  const lookback = mode === "active" ? ACTIVE_LOOKBACK_DAYS * DAY_MS : 0;
+  return {
+    start: start.toISOString(),
+    end: new Date(start.getTime() + DAY_MS).toISOString(),
+    scanFrom: new Date(start.getTime() - lookback).toISOString(),
+  };
+}
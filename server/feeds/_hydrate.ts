@@ -1,6 +1,15 @@
 import { views } from "$hatk";
 import type { StatusRecord, StatusView } from "$hatk";
 import type { BaseContext, Row } from "$hatk";
+import { extractMentions } from "./_mentions.ts";
+import { extractTags } from "./_tags.ts";
+
+// `via` is free text from whichever client wrote the record — drop control
+// characters and cap it so other clients can't inject junk into our views.
+function cleanVia(via: string | undefined): string | undefined {
+  const cleaned = via?.replace(/[\u0000-\u001f\u007f]/g, "").trim().slice(0, 64);
+  return cleaned || undefined;
+}
 
 export async function hydrateStatuses(
   ctx: BaseContext,
@@ -10,6 +19,9 @@ export async function hydrateStatuses(
 
   return items.map((item) => {
     const expiresDate = item.value.expires ? new Date(item.value.expires) : null;
+    const text = item.value.text?.trim() || undefined;
+    const tags = extractTags(text);
+    const mentions = extractMentions(text);
 
     return views.statusView({
       uri: item.uri,
@@ -17,8 +29,13 @@ export async function hydrateStatuses(
       did: item.did,
       handle: item.handle ?? item.did,
       emoji: item.value.emoji,
-      text: item.value.text,
+      // other clients may write "" or whitespace for "no text" — treat it as absent
+      text,
+      tags: tags.length > 0 ? tags : undefined,
+      mentions: mentions.length > 0 ? mentions : undefined,
       expires: item.value.expires,
+      context: item.value.context?.trim() || undefined,
+      via: cleanVia(item.value.via),
       createdAt: item.value.createdAt,
       indexedAt: item.indexed_at ?? item.value.createdAt,
       expired: expiresDate ? expiresDate < now : false,
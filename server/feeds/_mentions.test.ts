@@ -0,0 +1,46 @@
+import { describe, expect, it } from "vite-plus/test";
+import { extractMentions } from "./_mentions.ts";
+
+describe("extractMentions", () => {
+  it("finds handles at the start, after spaces, parens and quotes", () => {
+    const text = '@Alice.bsky.social and (@bob.test) "@carol.dev" “@dan.example.com”';
+    expect(extractMentions(text)).toEqual([
+      "alice.bsky.social",
+      "bob.test",
+      "carol.dev",
+      "dan.example.com",
+    ]);
+  });
+
+  it("ignores emails and url paths", () => {
+    const text = "mail bob@example.com or see x.com/@bob.test and bsky.app/profile/@a.b";
+    expect(extractMentions(text)).toEqual([]);
+  });
+
+  it("drops trailing punctuation", () => {
+    const text = "pairing with @alice.bsky.social. then @bob.test, @carol.dev! @dan.io's";
+    expect(extractMentions(text)).toEqual([
+      "alice.bsky.social",
+      "bob.test",
+      "carol.dev",
+      "dan.io",
+    ]);
+  });
+
+  it("needs a dotted handle that ends cleanly", () => {
+    expect(extractMentions("@bob @bob. @bob.123 @bob.test_x @-bob.test")).toEqual([]);
+  });
+
+  it("leaves mentions inside markdown links to the link", () => {
+    expect(extractMentions("[ask @bob.test](https://example.com) or @carol.dev")).toEqual(["carol.dev"]);
+  });
+
+  it("reads text, not html: an entity before @ isn't a quote", () => {
+    expect(extractMentions("&quot;@bob.test&quot;")).toEqual([]);
+    expect(extractMentions('"@bob.test"')).toEqual(["bob.test"]);
+  });
+
+  it("dedupes case variants", () => {
+    expect(extractMentions("@Bob.Test @bob.test")).toEqual(["bob.test"]);
+  });
+});
@@ -0,0 +1,19 @@
+// @handle mentions in status text. a mention opens the text or follows whitespace, "("
+// or a quote, so emails (bob@example.com) and url paths (x.com/@bob) aren't mentions.
+// handles are domains: at least one dot, and a tld starting with a letter, so trailing
+// punctuation falls off. kept in sync with the linkifier in app/lib/utils/emoji.ts.
+const MENTION =
+  /(?<=^|[\s("'“‘])@((?:[a-zA-Z0-9](?:[a-zA-Z0-9-]{0,61}[a-zA-Z0-9])?\.)+[a-zA-Z](?:[a-zA-Z0-9-]{0,61}[a-zA-Z0-9])?)(?![\w-])/g;
+// text inside [label](url) belongs to the link, not to a mention
+const MARKDOWN_LINK = /\[[^\]]+\]\([^)]+\)/g;
+const MAX_HANDLE_LENGTH = 253;
+
+// lowercased, deduped, in order of appearance
+export function extractMentions(text: string | undefined): string[] {
+  if (!text) return [];
+  const handles = new Set<string>();
+  for (const match of text.replace(MARKDOWN_LINK, " ").matchAll(MENTION)) {
+    if (match[1].length <= MAX_HANDLE_LENGTH) handles.add(match[1].toLowerCase());
+  }
+  return [...handles];
+}
@@ -0,0 +1,39 @@
+import { describe, expect, it } from "vite-plus/test";
+import { extractTags, normalizeTag } from "./_tags.ts";
+
+describe("extractTags", () => {
+  it("lowercases, dedupes and stops at punctuation", () => {
+    expect(extractTags("#focus, #OnVacation! (#focus) #done.")).toEqual(["focus", "onvacation", "done"]);
+  });
+
+  it("accepts a tag right after an emoji but not after a word", () => {
+    expect(extractTags("🎧#music")).toEqual(["music"]);
+    expect(extractTags("foo#bar")).toEqual([]);
+  });
+
+  it("ignores url fragments, html entities and doubled hashes", () => {
+    expect(extractTags("see x.com/#top and a&#39;b and ##double")).toEqual([]);
+  });
+
+  it("rejects digits-only tags but keeps ones that mix in letters", () => {
+    expect(extractTags("#123 #2024goals")).toEqual(["2024goals"]);
+  });
+
+  it("caps tags at 64 characters", () => {
+    expect(extractTags(`#${"a".repeat(64)}`)).toEqual(["a".repeat(64)]);
+    expect(extractTags(`#${"a".repeat(65)}`)).toEqual([]);
+  });
+
+  it("folds unicode case and normalization", () => {
+    expect(extractTags("#Été #café")).toEqual(["été", "café"]);
+  });
+});
+
+describe("normalizeTag", () => {
+  it("strips the hash and rejects non-tags", () => {
+    expect(normalizeTag("#Focus")).toBe("focus");
+    expect(normalizeTag("two words")).toBeNull();
+    expect(normalizeTag("#42")).toBeNull();
+    expect(normalizeTag("")).toBeNull();
+  });
+});
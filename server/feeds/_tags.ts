@@ -0,0 +1,23 @@
+// #hashtags in status text. a tag can't follow a letter, digit or `&`/`/`/`#`, so
+// `foo#bar`, html entities and url fragments (x.com/#top) aren't tags, but one right
+// after an emoji (🎧#focus) is. kept in sync with the linkifier in app/lib/utils/emoji.ts.
+const HASHTAG = /(?<![\p{L}\p{M}\p{N}_&/#])#([\p{L}\p{M}\p{N}_]+)/gu;
+export const MAX_TAG_LENGTH = 64;
+
+// null for anything that isn't a usable tag: too long, or digits only (#1, #2024)
+export function normalizeTag(tag: string): string | null {
+  const normalized = tag.replace(/^#/, "").normalize("NFC").toLowerCase();
+  if (!/^[\p{L}\p{M}\p{N}_]+$/u.test(normalized)) return null;
+  if ([...normalized].length > MAX_TAG_LENGTH || /^\p{N}+$/u.test(normalized)) return null;
+  return normalized;
+}
+
+export function extractTags(text: string | undefined): string[] {
+  if (!text) return [];
+  const tags = new Set<string>();
+  for (const match of text.matchAll(HASHTAG)) {
+    const tag = normalizeTag(match[1]);
+    if (tag) tags.add(tag);
+  }
+  return [...tags];
+}
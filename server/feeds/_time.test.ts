@@ -0,0 +1,78 @@
+import { DatabaseSync } from "node:sqlite";
+import { beforeEach, describe, expect, it } from "vite-plus/test";
+import { instant, liveAt } from "./_time.ts";
+
+// the point is how sqlite itself reads these timestamps, so run the clauses for real
+const db = new DatabaseSync(":memory:");
+db.exec(`CREATE TABLE "io.zzstoatzz.status.record" (uri TEXT, did TEXT, created_at TEXT, expires TEXT)`);
+
+const insert = (uri: string, createdAt: string, expires: string | null = null, did = "did:plc:a") =>
+  db
+    .prepare(`INSERT INTO "io.zzstoatzz.status.record" VALUES (?, ?, ?, ?)`)
+    .run(uri, did, createdAt, expires);
+
+// hatk numbers its placeholders $1, $2…; sqlite spells those ?1, ?2…
+const select = (where: string, ...params: string[]) =>
+  (
+    db
+      .prepare(
+        `SELECT t.uri FROM "io.zzstoatzz.status.record" t
+         WHERE ${where.replace(/\$(\d+)/g, "?$1")} ORDER BY t.uri`,
+      )
+      .all(...params) as { uri: string }[]
+  ).map((row) => row.uri);
+
+beforeEach(() => {
+  db.exec(`DELETE FROM "io.zzstoatzz.status.record"`);
+});
+
+describe("liveAt", () => {
+  beforeEach(() => {
+    // 12:00 in new york is 17:00Z, which sorts *before* "2025-03-01T16:…Z" as a string
+    insert("offset", "2025-03-01T09:00:00-05:00", "2025-03-01T12:00:00-05:00");
+    insert("forever", "2025-03-01T09:00:00Z");
+    insert("garbled", "2025-03-01T09:00:00Z", "whenever");
+  });
+
+  it("compares expiry as an instant, not a string", () => {
+    expect(select(liveAt("$1"), "2025-03-01T16:30:00.000Z")).toEqual(["forever", "garbled", "offset"]);
+  });
+
+  it("treats the expiry instant itself as expired", () => {
+    expect(select(liveAt("$1"), "2025-03-01T16:59:59.999Z")).toContain("offset");
+    expect(select(liveAt("$1"), "2025-03-01T17:00:00.000Z")).not.toContain("offset");
+  });
+
+  it("keeps statuses with no or unparseable expiry live", () => {
+    expect(select(liveAt("$1"), "2099-01-01T00:00:00.000Z")).toEqual(["forever", "garbled"]);
+  });
+});
+
+describe("instant", () => {
+  it("picks an author's latest status across offsets", () => {
+    // 10:00-05:00 is 15:00Z: later than 14:00Z, though it sorts first as text
+    insert("earlier", "2025-03-01T14:00:00.000Z");
+    insert("later", "2025-03-01T10:00:00-05:00");
+    insert("other", "2025-03-01T08:00:00Z", null, "did:plc:b");
+
+    const latest = `${instant("t.created_at")} = (
+      SELECT MAX(${instant("latest.created_at")}) FROM "io.zzstoatzz.status.record" latest
+      WHERE latest.did = t.did
+    )`;
+    expect(select(latest)).toEqual(["later", "other"]);
+  });
+
+  it("bounds a UTC day by instant", () => {
+    insert("late-local", "2025-02-28T23:30:00-05:00"); // 04:30Z on the 1st
+    insert("early-local", "2025-03-01T00:30:00+01:00"); // 23:30Z on the 28th
+    insert("midnight", "2025-03-01T00:00:00Z");
+    insert("next-day", "2025-03-02T00:00:00.000Z");
+
+    const day = `${instant("t.created_at")} >= ${instant("$1")}
+      AND ${instant("t.created_at")} < ${instant("$2")}`;
+    expect(select(day, "2025-03-01T00:00:00.000Z", "2025-03-02T00:00:00.000Z")).toEqual([
+      "late-local",
+      "midnight",
+    ]);
+  });
+});
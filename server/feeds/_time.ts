@@ -0,0 +1,11 @@
+// record timestamps are whatever the writing client sent: "Z" or "-05:00", with or
+// without fractional seconds. those don't order as strings, so every comparison goes
+// through julianday(). an expires sqlite can't parse reads as "never expires", the
+// same as hydrate's `new Date(expires) < now`.
+
+// `expr` as an instant, comparable across offsets
+export const instant = (expr: string) => `julianday(${expr})`;
+
+// the row aliased `alias` hadn't expired yet at the instant held by `param`
+export const liveAt = (param: string, alias = "t") =>
+  `(${instant(`${alias}.expires`)} IS NULL OR ${instant(`${alias}.expires`)} > ${instant(param)})`;
@@ -15,10 +15,11 @@ export default defineFeed({
       return ok({ uris: [], cursor: undefined });
     }
 
+    // handles are case-insensitive; the index stores them lowercased
     if (!actor.startsWith("did:")) {
       const rows = (await ctx.db.query(
         `SELECT did FROM _repos WHERE handle = $1`,
-        [actor],
+        [actor.toLowerCase()],
       )) as { did: string }[];
       if (rows[0]?.did) {
         actor = rows[0].did;
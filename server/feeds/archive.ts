@@ -0,0 +1,58 @@
+import { defineFeed } from "$hatk";
+import { archiveWindow } from "./_archive.ts";
+import { hydrateStatuses } from "./_hydrate.ts";
+import { instant, liveAt } from "./_time.ts";
+
+// one UTC day of history. mode=created (default) is statuses created that day;
+// mode=active is statuses that were live at any point during it — created before the
+// day ends, and not yet ended (expired or replaced by the author's next status) when
+// it starts. a date that isn't a real day in the archive's range is an error that
+// names the range, not an empty page.
+export default defineFeed({
+  collection: "io.zzstoatzz.status.record",
+  label: "Archive",
+
+  hydrate: hydrateStatuses,
+
+  async generate(ctx) {
+    const mode = ctx.params.mode === "active" ? "active" : "created";
+    const [oldest] = (await ctx.db.query(
+      `SELECT date(MIN(${instant("t.created_at")})) AS first FROM "io.zzstoatzz.status.record" t
+       LEFT JOIN _repos r ON t.did = r.did
+       WHERE (r.status IS NULL OR r.status != 'takendown')`,
+      [],
+    )) as { first: string | null }[];
+    const day = archiveWindow(String(ctx.params.date ?? ""), mode, {
+      first: oldest?.first ?? null,
+      today: new Date().toISOString().slice(0, 10),
+    });
+    if ("error" in day) throw new Error(day.error);
+
+    const window =
+      mode === "active"
+        ? `${instant("t.created_at")} < ${instant("$2")}
+           AND ${instant("t.created_at")} >= ${instant("$3")}
+           AND ${liveAt("$1")}
+           AND NOT EXISTS (
+             SELECT 1 FROM "io.zzstoatzz.status.record" n
+             WHERE n.did = t.did
+               AND ${instant("n.created_at")} > ${instant("t.created_at")}
+               AND ${instant("n.created_at")} <= ${instant("$1")}
+           )`
+        : `${instant("t.created_at")} >= ${instant("$1")}
+           AND ${instant("t.created_at")} < ${instant("$2")}`;
+
+    const { rows, cursor } = await ctx.paginate<{ uri: string }>(
+      `SELECT t.uri, t.cid, t.created_at FROM "io.zzstoatzz.status.record" t
+       LEFT JOIN _repos r ON t.did = r.did
+       WHERE (r.status IS NULL OR r.status != 'takendown')
+       AND ${window}`,
+      {
+        params: mode === "active" ? [day.start, day.end, day.scanFrom] : [day.start, day.end],
+        orderBy: "t.created_at",
+      },
+    );
+
+    return ctx.ok({ uris: rows.map((r) => r.uri), cursor });
+  },
+});
@@ -0,0 +1,49 @@
+import { defineFeed } from "$hatk";
+import { hydrateStatuses } from "./_hydrate.ts";
+import { instant, liveAt } from "./_time.ts";
+
+const MAX_ACTORS = 100;
+
+export default defineFeed({
+  collection: "io.zzstoatzz.status.record",
+  label: "Current Statuses",
+
+  hydrate: hydrateStatuses,
+
+  // one row per author: their latest status, and only while it hasn't expired.
+  // an author whose latest status expired has no current status, so they drop out
+  // rather than falling back to an older one. each surviving row is that author's
+  // newest, so paginating on created_at pages by author.
+  async generate(ctx) {
+    const now = new Date().toISOString();
+
+    // `actors` narrows the feed to some authors (comma-separated DIDs), so a batch of
+    // current statuses is one query
+    const actors = String(ctx.params.actors ?? "")
+      .split(",")
+      .map((did) => did.trim())
+      .filter((did) => did.startsWith("did:"))
+      .slice(0, MAX_ACTORS);
+    if (ctx.params.actors && actors.length === 0) {
+      return ctx.ok({ uris: [], cursor: undefined });
+    }
+    const only = actors.length
+      ? `AND t.did IN (${actors.map((_, i) => `$${i + 2}`).join(", ")})`
+      : "";
+
+    const { rows, cursor } = await ctx.paginate<{ uri: string }>(
+      `SELECT t.uri, t.cid, t.created_at FROM "io.zzstoatzz.status.record" t
+       LEFT JOIN _repos r ON t.did = r.did
+       WHERE (r.status IS NULL OR r.status != 'takendown')
+       ${only}
+       AND ${instant("t.created_at")} = (
+         SELECT MAX(${instant("latest.created_at")}) FROM "io.zzstoatzz.status.record" latest
+         WHERE latest.did = t.did
+       )
+       AND ${liveAt("$1")}`,
+      { params: [now, ...actors], orderBy: "t.created_at" },
+    );
+
+    return ctx.ok({ uris: rows.map((r) => r.uri), cursor });
+  },
+});
@@ -0,0 +1,41 @@
+import { defineFeed } from "$hatk";
+import { hydrateStatuses } from "./_hydrate.ts";
+
+const MAX_EMOJI = 10;
+
+const CUSTOM_SLUG = /^custom:[a-z0-9][a-z0-9_-]*$/i;
+const UNICODE_EMOJI = /^(?:\p{Extended_Pictographic}|\p{Regional_Indicator})/u;
+
+// "channels": everyone's statuses that use one of the given emoji, e.g. custom:music
+export default defineFeed({
+  collection: "io.zzstoatzz.status.record",
+  label: "Statuses by Emoji",
+
+  hydrate: hydrateStatuses,
+
+  async generate(ctx) {
+    const emojis = [
+      ...new Set(
+        String(ctx.params.emoji ?? "")
+          .split(",")
+          .map((e) => e.trim())
+          .filter((e) => CUSTOM_SLUG.test(e) || UNICODE_EMOJI.test(e)),
+      ),
+    ].slice(0, MAX_EMOJI);
+
+    if (emojis.length === 0) {
+      return ctx.ok({ uris: [], cursor: undefined });
+    }
+
+    const placeholders = emojis.map((_, i) => `$${i + 1}`).join(", ");
+    const { rows, cursor } = await ctx.paginate<{ uri: string }>(
+      `SELECT t.uri, t.cid, t.created_at FROM "io.zzstoatzz.status.record" t
+       LEFT JOIN _repos r ON t.did = r.did
+       WHERE (r.status IS NULL OR r.status != 'takendown')
+       AND t.emoji IN (${placeholders})`,
+      { params: emojis, orderBy: "t.created_at" },
+    );
+
+    return ctx.ok({ uris: rows.map((r) => r.uri), cursor });
+  },
+});
@@ -0,0 +1,23 @@
+import { defineFeed } from "$hatk";
+import { hydrateStatuses } from "./_hydrate.ts";
+
+export default defineFeed({
+  collection: "io.zzstoatzz.status.record",
+  label: "Oldest Status",
+
+  hydrate: hydrateStatuses,
+
+  // the instance's first status: where the archive starts
+  async generate(ctx) {
+    const rows = (await ctx.db.query(
+      `SELECT t.uri FROM "io.zzstoatzz.status.record" t
+       LEFT JOIN _repos r ON t.did = r.did
+       WHERE (r.status IS NULL OR r.status != 'takendown')
+       ORDER BY t.created_at ASC
+       LIMIT 1`,
+      [],
+    )) as { uri: string }[];
+
+    return ctx.ok({ uris: rows.map((r) => r.uri), cursor: undefined });
+  },
+});
@@ -1,5 +1,6 @@
 import { defineFeed } from "$hatk";
 import { hydrateStatuses } from "./_hydrate.ts";
+import { liveAt } from "./_time.ts";
 
 export default defineFeed({
   collection: "io.zzstoatzz.status.record",
@@ -8,11 +9,17 @@ export default defineFeed({
   hydrate: hydrateStatuses,
 
   async generate(ctx) {
+    // expired statuses are history, not "what's happening" — keep them out of
+    // the global feed unless the client explicitly asks for the full log
+    const includeExpired = String(ctx.params.includeExpired) === "true";
+    const now = new Date().toISOString();
+
     const { rows, cursor } = await ctx.paginate<{ uri: string }>(
       `SELECT t.uri, t.cid, t.created_at FROM "io.zzstoatzz.status.record" t
        LEFT JOIN _repos r ON t.did = r.did
-       WHERE (r.status IS NULL OR r.status != 'takendown')`,
-      { orderBy: "t.created_at" },
+       WHERE (r.status IS NULL OR r.status != 'takendown')
+       ${includeExpired ? "" : `AND ${liveAt("$1")}`}`,
+      { params: includeExpired ? [] : [now], orderBy: "t.created_at" },
     );
 
     return ctx.ok({ uris: rows.map((r) => r.uri), cursor });
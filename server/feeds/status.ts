@@ -0,0 +1,28 @@
+import { defineFeed } from "$hatk";
+import { hydrateStatuses } from "./_hydrate.ts";
+
+export default defineFeed({
+  collection: "io.zzstoatzz.status.record",
+  label: "Single Status",
+
+  hydrate: hydrateStatuses,
+
+  // one status by at-uri, hydrated like every other feed: backs the share page,
+  // its json twin and the per-status embed
+  async generate(ctx) {
+    const uri = ctx.params.uri;
+    if (!uri) {
+      return ctx.ok({ uris: [], cursor: undefined });
+    }
+
+    const rows = (await ctx.db.query(
+      `SELECT t.uri FROM "io.zzstoatzz.status.record" t
+       LEFT JOIN _repos r ON t.did = r.did
+       WHERE t.uri = $1
+         AND (r.status IS NULL OR r.status != 'takendown')`,
+      [uri],
+    )) as { uri: string }[];
+
+    return ctx.ok({ uris: rows.map((r) => r.uri), cursor: undefined });
+  },
+});
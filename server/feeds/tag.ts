@@ -0,0 +1,62 @@
+import { defineFeed } from "$hatk";
+import { hydrateStatuses } from "./_hydrate.ts";
+import { extractTags, normalizeTag } from "./_tags.ts";
+
+const BATCH = 100;
+const MAX_SCANNED = 2_000;
+
+// everyone's statuses that mention a #tag
+export default defineFeed({
+  collection: "io.zzstoatzz.status.record",
+  label: "Statuses by Tag",
+
+  hydrate: hydrateStatuses,
+
+  // sqlite can't tokenize, so LIKE picks candidates and extractTags decides. LIKE only
+  // folds ascii case and knows nothing of NFC, so the pattern uses just the tag's ascii
+  // prefix (#été searches every tagged status; #café searches #caf). near misses are
+  // dropped here rather than after paginating, so we page ourselves: keep scanning until
+  // the page is full, the candidates run out, or MAX_SCANNED rows have been looked at.
+  async generate(ctx) {
+    const tag = normalizeTag(String(ctx.params.tag ?? ""));
+    if (!tag) {
+      return ctx.ok({ uris: [], cursor: undefined });
+    }
+    const limit = Math.min(Math.max(Number(ctx.params.limit) || 30, 1), 100);
+
+    const prefix = /^[\x00-\x7f]*/.exec(tag)![0];
+    const pattern = `%#${prefix.replace(/[\\%_]/g, (c) => `\\${c}`)}%`;
+
+    // cursor is `{created_at}::{uri}` of the last row scanned
+    let [before, beforeUri] = String(ctx.params.cursor ?? "").split("::");
+    const uris: string[] = [];
+    let scanned = 0;
+    let exhausted = false;
+
+    while (uris.length < limit && scanned < MAX_SCANNED) {
+      const rows = (await ctx.db.query(
+        `SELECT t.uri, t.created_at, t.text FROM "io.zzstoatzz.status.record" t
+         LEFT JOIN _repos r ON t.did = r.did
+         WHERE (r.status IS NULL OR r.status != 'takendown')
+         AND t.text LIKE $1 ESCAPE '\\'
+         ${before && beforeUri ? "AND (t.created_at < $2 OR (t.created_at = $2 AND t.uri < $3))" : ""}
+         ORDER BY t.created_at DESC, t.uri DESC
+         LIMIT ${BATCH}`,
+        before && beforeUri ? [pattern, before, beforeUri] : [pattern],
+      )) as { uri: string; created_at: string; text: string | null }[];
+
+      for (const row of rows) {
+        scanned++;
+        [before, beforeUri] = [row.created_at, row.uri];
+        if (extractTags(row.text ?? undefined).includes(tag)) uris.push(row.uri);
+        if (uris.length === limit) break;
+      }
+      if (rows.length < BATCH && uris.length < limit) {
+        exhausted = true;
+        break;
+      }
+    }
+
+    return ctx.ok({ uris, cursor: exhausted || !before ? undefined : `${before}::${beforeUri}` });
+  },
+});